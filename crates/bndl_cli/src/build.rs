@@ -0,0 +1,183 @@
+use bndl_convert::{Converter, CreateConverterOptions, TsConfigJson};
+use bndl_deps::Manager;
+use log::debug;
+use std::path::{Path, PathBuf};
+
+use crate::bundle::Bundler;
+use crate::transpile::{TranspileOptions, Transpiler};
+
+/// Options shared by every referenced project that is built
+pub struct BuildOptions {
+    pub converter: CreateConverterOptions,
+    pub transpile: TranspileOptions,
+}
+
+/// Builds the projects referenced by a `tsconfig.json` in dependency order, similar to `tsc -b`
+pub struct ProjectBuilder<'a> {
+    manager: &'a Manager,
+}
+
+impl<'a> ProjectBuilder<'a> {
+    pub fn new(manager: &'a Manager) -> Self {
+        Self { manager }
+    }
+
+    fn canonicalize(config_path: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        config_path
+            .canonicalize()
+            .map_err(|err| format!("Failed to resolve project {:?}: {err}", config_path).into())
+    }
+
+    /// Walks the reference graph depth first so every project comes after the projects it references
+    fn visit(
+        &self,
+        config_path: &Path,
+        visiting: &mut Vec<PathBuf>,
+        order: &mut Vec<PathBuf>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let config_path = Self::canonicalize(config_path)?;
+
+        if order.contains(&config_path) {
+            return Ok(());
+        }
+
+        if visiting.contains(&config_path) {
+            let cycle = visiting
+                .iter()
+                .skip_while(|path| **path != config_path)
+                .chain([&config_path])
+                .map(|path| path.to_string_lossy())
+                .collect::<Vec<_>>();
+
+            return Err(format!("Circular project reference: {}", cycle.join(" -> ")).into());
+        }
+
+        visiting.push(config_path.clone());
+
+        let project_dir = config_path.parent().unwrap();
+        let tsconfig = TsConfigJson::from_path(&config_path, project_dir, self.manager)?;
+        for reference in tsconfig.resolve_references(&config_path) {
+            self.visit(&reference, visiting, order)?;
+        }
+
+        visiting.pop();
+        order.push(config_path);
+
+        Ok(())
+    }
+
+    /// Determines the configs of all referenced projects in the order they should be built,
+    /// excluding the given project itself
+    pub fn determine_build_order(
+        &self,
+        config_path: &Path,
+    ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        let mut order = vec![];
+        self.visit(config_path, &mut vec![], &mut order)?;
+
+        // The project itself always comes last since it references everything before it
+        order.pop();
+
+        Ok(order)
+    }
+
+    /// Compiles a single referenced project with its own converter, resolving the paths
+    /// in its config relative to the project itself
+    fn build_project(
+        &self,
+        config_path: &Path,
+        opts: &BuildOptions,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let converter = Converter::from_path(
+            config_path,
+            CreateConverterOptions {
+                manager: Some(self.manager.clone()),
+                project_dir: config_path.parent().map(Path::to_path_buf),
+                ..opts.converter.clone()
+            },
+        )?;
+        let bundler = Bundler::new(Some(self.manager))?;
+        let transpiler = Transpiler::new(&converter, &bundler);
+
+        transpiler.transpile(TranspileOptions {
+            input_path: PathBuf::from("."),
+            out_dir: converter.determine_out_dir(None),
+            config_path: config_path.to_path_buf(),
+            // Only the output directory of the referenced project itself is cleaned
+            clean: opts.transpile.clean,
+            // Referenced projects end up in the bundle of the project using them instead
+            bundle: false,
+            ..opts.transpile.clone()
+        })
+    }
+
+    /// Builds all projects referenced by the given config, but not the project itself
+    pub fn build_references(
+        &self,
+        config_path: &Path,
+        opts: &BuildOptions,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for reference in self.determine_build_order(config_path)? {
+            debug!("Building referenced project {:?}", reference);
+            self.build_project(&reference, opts)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::{create_manager, TestProject};
+
+    #[test]
+    fn determine_build_order_puts_references_first() {
+        let project = TestProject::new(&[
+            (
+                "app/tsconfig.json",
+                r#"{ "references": [{ "path": "../lib" }, { "path": "../utils" }] }"#,
+            ),
+            (
+                "lib/tsconfig.json",
+                r#"{ "references": [{ "path": "../utils" }] }"#,
+            ),
+            ("utils/tsconfig.json", "{}"),
+        ]);
+        let manager = create_manager(project.path());
+        let root = project.path().canonicalize().unwrap();
+
+        let order = ProjectBuilder::new(&manager)
+            .determine_build_order(&project.path().join("app/tsconfig.json"))
+            .unwrap();
+
+        assert_eq!(
+            order,
+            vec![
+                root.join("utils/tsconfig.json"),
+                root.join("lib/tsconfig.json")
+            ]
+        );
+    }
+
+    #[test]
+    fn determine_build_order_rejects_circular_references() {
+        let project = TestProject::new(&[
+            (
+                "app/tsconfig.json",
+                r#"{ "references": [{ "path": "../lib" }] }"#,
+            ),
+            (
+                "lib/tsconfig.json",
+                r#"{ "references": [{ "path": "../app" }] }"#,
+            ),
+        ]);
+        let manager = create_manager(project.path());
+
+        let err = ProjectBuilder::new(&manager)
+            .determine_build_order(&project.path().join("app/tsconfig.json"))
+            .unwrap_err();
+
+        assert!(err.to_string().starts_with("Circular project reference"));
+    }
+}
@@ -2,8 +2,7 @@ use bndl_convert::{Converter, CreateConverterOptions};
 use bndl_deps::Manager;
 use log::debug;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use std::env;
-use std::path::PathBuf;
+use std::path::Path;
 
 use crate::utils::fs::copy_dir_all;
 
@@ -23,8 +22,15 @@ impl Bundler {
         })
     }
 
-    pub fn bundle(&self, app_out_path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-        let app_dir = env::current_dir().unwrap_or(PathBuf::from("."));
+    pub fn manager(&self) -> &Manager {
+        &self.manager
+    }
+
+    pub fn bundle(
+        &self,
+        app_dir: &Path,
+        app_out_path: &Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         let dependencies = self
             .manager
             .fetch_used_dependencies(&app_dir.join("package.json"));
@@ -41,9 +47,9 @@ impl Bundler {
             let source = match Converter::from_path(
                 &config_path,
                 CreateConverterOptions {
-                    minify_output: None,
-                    enable_experimental_swc_declarations: None,
                     manager: Some(self.manager.clone()),
+                    project_dir: Some(path.clone()),
+                    ..Default::default()
                 },
             ) {
                 Ok(ref converter) => {
@@ -7,12 +7,15 @@ use clap::{ArgAction, Command};
 use human_panic::setup_panic;
 use std::{path::PathBuf, process};
 
-use transpile::{TranspileOptions, Transpiler};
+use transpile::{DeclarationErrors, TranspileOptions, Transpiler};
 
+use crate::build::{BuildOptions, ProjectBuilder};
 use crate::bundle::Bundler;
 
+mod build;
 mod bundle;
 mod transpile;
+mod typecheck;
 mod utils;
 
 fn cli() -> Command {
@@ -28,6 +31,13 @@ fn cli() -> Command {
                 .help("The path to the project config file")
                 .action(ArgAction::Set),
         )
+        .arg(
+            clap::Arg::new("build")
+                .short('b')
+                .long("build")
+                .help("Build all referenced projects in dependency order before building the project itself")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             clap::Arg::new("outDir")
                 .long("outDir")
@@ -52,6 +62,47 @@ fn cli() -> Command {
                 .help("Disable automatic bundling of internal monorepo dependencies")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            clap::Arg::new("copy-dts")
+                .long("copy-dts")
+                .help("Copy declaration files (.d.ts) that live in the root directory to the output folder")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("tsc-declarations")
+                .long("tsc-declarations")
+                .help("Emit declaration files using `tsc` instead of SWC's isolated declarations")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("declaration-errors")
+                .long("declaration-errors")
+                .help("Whether failures while emitting declarations should fail the build or only warn")
+                .value_parser(["error", "warn"])
+                .default_value("error")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            clap::Arg::new("typecheck")
+                .long("typecheck")
+                .help("Type check the project with `tsc` in parallel and fail the build on type errors")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("swcrc")
+                .long("swcrc")
+                .help("Merge a .swcrc over the options converted from the tsconfig.json instead of the bndl.swcrc next to it, which is merged automatically. Defaults to the .swcrc in the current directory")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value(".swcrc")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            clap::Arg::new("strict-config")
+                .long("strict-config")
+                .help("Fail when the tsconfig.json contains options that can't be translated (exactly)")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             clap::Arg::new("minify")
                 .short('m')
@@ -74,6 +125,8 @@ fn cli() -> Command {
         )
 }
 
+// `human-panic` still references the deprecated `PanicInfo` alias in its macro
+#[allow(deprecated)]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
     setup_panic!();
@@ -97,14 +150,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
 
     let manager = Manager::new()?;
-    let converter = Converter::from_path(
-        &PathBuf::from(config_path),
-        CreateConverterOptions {
-            minify_output: Some(matches.get_flag("minify")),
-            enable_experimental_swc_declarations: None,
-            manager: Some(manager.clone()),
-        },
-    )?;
+    let converter_options = CreateConverterOptions {
+        minify_output: Some(matches.get_flag("minify")),
+        enable_experimental_swc_declarations: Some(!matches.get_flag("tsc-declarations")),
+        manager: Some(manager.clone()),
+        swcrc_path: matches.get_one::<String>("swcrc").map(PathBuf::from),
+        project_dir: None,
+    };
+    let converter = Converter::from_path(&PathBuf::from(config_path), converter_options.clone())?;
+
+    if matches.get_flag("strict-config") {
+        converter.check_strict_config()?;
+    }
+
     let bundler = Bundler::new(Some(&manager))?;
     let transpiler = Box::new(Transpiler::new(&converter, &bundler));
 
@@ -114,7 +172,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // If requested, only bundle the internal dependencies
     if matches.get_flag("only-bundle") {
-        if let Err(err) = bundler.bundle(&out_dir) {
+        if let Err(err) = bundler.bundle(converter.project_dir(), &out_dir) {
             eprintln!("{err}");
         }
 
@@ -127,8 +185,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         config_path: PathBuf::from(config_path),
         bundle: !matches.get_flag("no-bundle"),
         clean: matches.get_flag("clean"),
+        copy_declarations: matches.get_flag("copy-dts"),
+        declaration_errors: match matches.get_one::<String>("declaration-errors") {
+            Some(mode) if mode == "warn" => DeclarationErrors::Warn,
+            _ => DeclarationErrors::Error,
+        },
+        typecheck: matches.get_flag("typecheck"),
     };
 
+    // Like `tsc -b`, build the referenced projects before the project itself
+    if matches.get_flag("build") {
+        let build_options = BuildOptions {
+            converter: converter_options,
+            transpile: transpile_options.clone(),
+        };
+
+        if let Err(err) = ProjectBuilder::new(&manager)
+            .build_references(&PathBuf::from(config_path), &build_options)
+        {
+            eprintln!("{err}");
+            process::exit(1)
+        }
+    }
+
     // If the watch flag is set, watch the input files for changes and recompile when they change
     if matches.get_flag("watch") {
         let exec = matches.get_one::<String>("exec");
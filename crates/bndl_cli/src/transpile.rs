@@ -1,26 +1,33 @@
-use bndl_convert::{Converter, GlobSetConfig, SerializableOptions};
+use bndl_convert::{
+    is_declaration_file, normalize_path, Converter, CreateConverterOptions, GlobSetConfig,
+    SerializableOptions,
+};
 use command_group::CommandGroup;
 use log::{debug, info};
 use notify::{self, RecursiveMode, Watcher};
 use rayon::prelude::*;
 use std::collections::HashSet;
 use std::path::PathBuf;
-use std::sync::Mutex;
-use std::{env, fs, process};
+use std::sync::{Mutex, OnceLock};
+use std::{fs, process};
 use std::{path::Path, sync::Arc};
-use swc_common::{SourceMap, GLOBALS};
+use swc_common::{comments::SingleThreadedComments, SourceMap, GLOBALS};
+use swc_ecma_ast::noop_pass;
+use swc_ecma_visit::visit_mut_pass;
 use walkdir::{DirEntry, WalkDir};
 
 use crate::bundle::Bundler;
+use crate::typecheck::TypeChecker;
 use crate::utils::sourcemap;
+use crate::utils::specifier::{InternalPackage, SpecifierRewriter};
 
 lazy_static! {
     static ref CREATED_DIRS: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
 }
 
 /// Removes the output directory if it exists
-pub fn clean_out_dir(out_path: &Path) -> Result<(), std::io::Error> {
-    let dir_to_delete = env::current_dir()?.join(out_path);
+pub fn clean_out_dir(project_dir: &Path, out_path: &Path) -> Result<(), std::io::Error> {
+    let dir_to_delete = project_dir.join(out_path);
     if dir_to_delete.exists() {
         debug!("Cleaning output directory: {:?}", dir_to_delete);
         fs::remove_dir_all(&dir_to_delete)?;
@@ -29,8 +36,13 @@ pub fn clean_out_dir(out_path: &Path) -> Result<(), std::io::Error> {
     Ok(())
 }
 
-/// Creates .d.ts files for the project
-fn create_tsc_dts(project: &Path, out_path: &Path) -> std::process::Output {
+/// Starts creating .d.ts files for the project in the background so it can run
+/// concurrently with the SWC compilation
+fn spawn_tsc_dts(
+    project_dir: &Path,
+    project: &Path,
+    out_path: &Path,
+) -> std::io::Result<process::Child> {
     let args = vec![
         "tsc",
         "-d",
@@ -43,22 +55,81 @@ fn create_tsc_dts(project: &Path, out_path: &Path) -> std::process::Output {
 
     std::process::Command::new("npx")
         .args(args)
-        .stdout(process::Stdio::inherit())
-        .stderr(process::Stdio::inherit())
-        .output()
-        .expect("Failed to execute command")
+        .current_dir(project_dir)
+        .stdout(process::Stdio::piped())
+        .stderr(process::Stdio::piped())
+        .spawn()
 }
 
-fn check_to_ignore_dir(entry: &DirEntry, glob_sets: &GlobSetConfig) -> bool {
-    glob_sets.exclude.is_match(entry.path()) || entry.file_name() == "node_modules"
+/// Waits for `tsc` to finish and reports its diagnostics when it failed
+fn wait_for_tsc_dts(
+    child: process::Child,
+    declaration_errors: DeclarationErrors,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    // `tsc` reports type errors on stdout
+    eprint!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    match declaration_errors {
+        DeclarationErrors::Error => {
+            Err(format!("Failed to emit declarations with tsc ({})", output.status).into())
+        }
+        DeclarationErrors::Warn => {
+            eprintln!(
+                "Warning: failed to emit declarations with tsc ({})",
+                output.status
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Reports the files that failed to compile, failing the build if requested
+fn report_compile_errors(
+    error_count: usize,
+    declaration_errors: DeclarationErrors,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match declaration_errors {
+        DeclarationErrors::Error => Err(format!("Failed to compile {error_count} file(s)").into()),
+        DeclarationErrors::Warn => {
+            eprintln!("Warning: failed to compile {error_count} file(s)");
+            Ok(())
+        }
+    }
 }
 
-fn check_to_ignore_file(file: &Path, glob_sets: &GlobSetConfig) -> bool {
-    glob_sets.exclude.is_match(file)
+fn check_to_ignore_dir(path: &Path, entry: &DirEntry, glob_sets: &GlobSetConfig) -> bool {
+    glob_sets.exclude.is_match(path) || entry.file_name() == "node_modules"
+}
+
+fn check_to_ignore_file(file: &Path, glob_sets: &GlobSetConfig, extensions: &[&str]) -> bool {
+    is_declaration_file(file)
+        || glob_sets.exclude.is_match(file)
         || (!glob_sets.include.is_empty() && !glob_sets.include.is_match(file))
         || file
             .extension()
-            .map_or(false, |ext| ext != "ts" && ext != "tsx" && ext != "js")
+            .is_some_and(|ext| !extensions.iter().any(|extension| ext == *extension))
+}
+
+/// How to treat `tsc` failures while emitting declarations
+#[derive(Clone, Copy, Default)]
+pub enum DeclarationErrors {
+    #[default]
+    Error,
+    Warn,
+}
+
+/// The files found in the input directory, grouped by how they are handled
+struct InputFiles {
+    paths: Vec<PathBuf>,
+    json_paths: Vec<PathBuf>,
+    declaration_paths: Vec<PathBuf>,
+    asset_paths: Vec<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -68,15 +139,17 @@ pub struct TranspileOptions {
     pub config_path: PathBuf,
     pub clean: bool,
     pub bundle: bool,
+    pub copy_declarations: bool,
+    pub declaration_errors: DeclarationErrors,
+    pub typecheck: bool,
 }
 
-fn prepare_input_path(input_path: &Path) -> PathBuf {
+fn prepare_input_path(input_path: &Path, app_dir: &Path) -> PathBuf {
     let mut input_path: PathBuf = input_path.to_path_buf();
-    let app_dir = env::current_dir().unwrap_or(PathBuf::from("."));
 
     // Remove the app directory from the input path and treat it as a relative path
-    if input_path.starts_with(&app_dir) {
-        input_path = input_path.strip_prefix(&app_dir).unwrap().to_path_buf();
+    if input_path.starts_with(app_dir) {
+        input_path = input_path.strip_prefix(app_dir).unwrap().to_path_buf();
     }
 
     // Remove the leading "./" if it exists, required for SWC to work
@@ -122,6 +195,13 @@ fn create_directory_if_not_exists(path: &Path) -> Result<(), std::io::Error> {
 pub struct Transpiler {
     converter: Converter,
     bundler: Bundler,
+    /// Root directory of the input files, determined once during the initial transpile so
+    /// subsequent (watch) compilations of single files end up in the same output location
+    root_dir: OnceLock<PathBuf>,
+    /// Extensions of the files we compile, based on `allowJs` in the tsconfig
+    input_extensions: Vec<&'static str>,
+    /// Internal packages that `paths` aliases might resolve into, only collected when needed
+    internal_packages: OnceLock<Vec<InternalPackage>>,
 }
 
 impl Transpiler {
@@ -129,6 +209,112 @@ impl Transpiler {
         Self {
             converter: converter.clone(),
             bundler: bundler.clone(),
+            root_dir: OnceLock::new(),
+            input_extensions: converter.determine_input_extensions(),
+            internal_packages: OnceLock::new(),
+        }
+    }
+
+    /// Only aliases can sensibly point into other packages, so we don't bother looking up
+    /// the internal packages when no `paths` are configured
+    fn determine_internal_packages(&self) -> &[InternalPackage] {
+        self.internal_packages.get_or_init(|| {
+            let has_paths = self
+                .converter
+                .tsconfig
+                .compilerOptions
+                .as_ref()
+                .is_some_and(|compiler_options| compiler_options.paths.is_some());
+
+            if has_paths {
+                InternalPackage::collect(
+                    self.bundler.manager(),
+                    self.converter.project_dir(),
+                    |package_dir| self.determine_package_root_dir(package_dir),
+                )
+            } else {
+                vec![]
+            }
+        })
+    }
+
+    /// Determines the root directory of another package the same way as for this project,
+    /// so we know which part of it ends up in its output
+    fn determine_package_root_dir(&self, package_dir: &Path) -> PathBuf {
+        let converter = match Converter::from_path(
+            &package_dir.join("tsconfig.json"),
+            CreateConverterOptions {
+                manager: Some(self.bundler.manager().clone()),
+                project_dir: Some(package_dir.to_path_buf()),
+                ..Default::default()
+            },
+        ) {
+            Ok(converter) => converter,
+            Err(err) => {
+                debug!("{err} for {:?}", package_dir);
+                return PathBuf::new();
+            }
+        };
+
+        let transpiler = Transpiler::new(&converter, &self.bundler);
+        let input_files =
+            transpiler.collect_input_files(Path::new("."), &converter.construct_globset(), false);
+
+        converter.determine_root_dir(&input_files.paths)
+    }
+
+    fn determine_root_dir(&self, input_files: &[PathBuf]) -> &Path {
+        self.root_dir.get_or_init(|| {
+            let root_dir = self.converter.determine_root_dir(input_files);
+            debug!("Root directory: {:?}", root_dir);
+
+            root_dir
+        })
+    }
+
+    /// Paths are kept relative to the project directory, which isn't necessarily
+    /// the current directory (eg. for referenced projects), so we resolve them on access
+    fn resolve(&self, path: &Path) -> PathBuf {
+        self.converter.project_dir().join(path)
+    }
+
+    fn has_input_extension(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| {
+            self.input_extensions
+                .iter()
+                .any(|extension| ext == *extension)
+        })
+    }
+
+    /// Determines where the input file should be written to in the output directory
+    /// by stripping the root directory from the input path, like `tsc` does
+    fn determine_output_file_path(&self, output_path: &Path, input_path: &Path) -> PathBuf {
+        let input_path = normalize_path(input_path);
+        // The root directory is determined from all the input files before anything is written
+        let root_dir = self.determine_root_dir(&[]);
+
+        output_path.join(input_path.strip_prefix(root_dir).unwrap_or(&input_path))
+    }
+
+    /// Declaration files are written next to the emitted code unless a `declarationDir` is specified
+    fn determine_declaration_file_path(
+        &self,
+        input_path: &Path,
+        output_file_path: &Path,
+    ) -> PathBuf {
+        let extension = self.converter.determine_declaration_extension(input_path);
+        let declaration_dir = self
+            .converter
+            .tsconfig
+            .compilerOptions
+            .as_ref()
+            .and_then(|compiler_options| compiler_options.declarationDir.as_ref());
+
+        match declaration_dir {
+            Some(declaration_dir) => self
+                .determine_output_file_path(Path::new(declaration_dir), input_path)
+                .with_extension(extension),
+            None => output_file_path.with_extension(extension),
         }
     }
 
@@ -138,37 +324,66 @@ impl Transpiler {
         compiler: &swc::Compiler,
         options: &swc::config::Options,
         glob_sets: &GlobSetConfig,
-    ) {
+    ) -> Result<(), String> {
         // Check if we should ignore the file based on the tsconfig exclude
         // We need to do this because the swc `exclude` is odd and doesn't work as expected
-        if check_to_ignore_file(input_path, glob_sets) {
-            return;
+        if check_to_ignore_file(input_path, glob_sets, &self.input_extensions) {
+            return Ok(());
         }
 
         let output_path = options.output_path.as_ref().unwrap();
-        let output_file_path = output_path.join(input_path).with_extension("js");
-        let source_map_path = output_file_path.with_extension("js.map");
+        let extension = self.converter.determine_output_extension(input_path);
+        let output_file_path = self
+            .determine_output_file_path(output_path, input_path)
+            .with_extension(extension);
+        let source_map_path = output_file_path.with_extension(format!("{extension}.map"));
+        let resolve_fully = self.converter.should_resolve_fully(input_path);
 
         // Create missing directories if they don't exist yet
-        if let Err(err) = create_directory_if_not_exists(&output_file_path) {
+        if let Err(err) = create_directory_if_not_exists(&self.resolve(&output_file_path)) {
             panic!("Failed to create directory: {:?}", err);
         }
 
+        let file_options = match self.converter.convert_for_file(options, input_path) {
+            Ok(file_options) => file_options,
+            Err(err) => {
+                eprintln!("{err}");
+                return Err(format!("Failed to compile {:?}", input_path));
+            }
+        };
         let extended_options = swc::config::Options {
             source_file_name: sourcemap::determine_source_file_name(
-                input_path,
-                output_file_path.parent().unwrap(),
+                &self.resolve(input_path),
+                &self.resolve(output_file_path.parent().unwrap()),
             ),
-            ..options.clone()
+            ..file_options
         };
 
         let transform_output = GLOBALS.set(&Default::default(), || {
             swc::try_with_handler(compiler.cm.clone(), Default::default(), |handler| {
                 compiler
                     .cm
-                    .load_file(input_path)
+                    .load_file(&self.resolve(input_path))
                     .map_err(Into::into)
-                    .and_then(|fm| compiler.process_js_file(fm, handler, &extended_options))
+                    .and_then(|fm| {
+                        compiler.process_js_with_custom_pass(
+                            fm,
+                            None,
+                            handler,
+                            &extended_options,
+                            SingleThreadedComments::default(),
+                            |_| noop_pass(),
+                            |_| {
+                                // Point relative imports to the emitted files
+                                visit_mut_pass(SpecifierRewriter::new(
+                                    &self.converter,
+                                    self.determine_internal_packages(),
+                                    input_path,
+                                    resolve_fully,
+                                ))
+                            },
+                        )
+                    })
             })
         });
 
@@ -183,11 +398,35 @@ impl Transpiler {
                         source_map.to_owned(),
                         source_file_name,
                         source_root,
+                        extension,
                     )
                 });
 
+                // Isolated declarations are passed along in the extra output by SWC
+                if let Some(declaration) = output
+                    .output
+                    .as_ref()
+                    .and_then(|extra| serde_json::from_str::<serde_json::Value>(extra).ok())
+                    .and_then(|extra| {
+                        extra["__swc_isolated_declarations__"]
+                            .as_str()
+                            .map(String::from)
+                    })
+                {
+                    let declaration_path = self.resolve(
+                        &self.determine_declaration_file_path(input_path, &output_file_path),
+                    );
+
+                    if let Err(err) = create_directory_if_not_exists(&declaration_path) {
+                        panic!("Failed to create directory: {:?}", err);
+                    }
+
+                    fs::write(&declaration_path, declaration)
+                        .unwrap_or_else(|_| panic!("Failed to write to {:?}", declaration_path));
+                }
+
                 if output.code.is_empty() {
-                    return;
+                    return Ok(());
                 }
 
                 if let Some(ref source_map) = source_map {
@@ -196,15 +435,21 @@ impl Transpiler {
                         .code
                         .push_str(&source_map_path.file_name().unwrap().to_string_lossy());
 
+                    let source_map_path = self.resolve(&source_map_path);
                     fs::write(&source_map_path, source_map)
                         .unwrap_or_else(|_| panic!("Failed to write to {:?}", source_map_path));
                 }
 
+                let output_file_path = self.resolve(&output_file_path);
                 fs::write(&output_file_path, &output.code)
                     .unwrap_or_else(|_| panic!("Failed to write to {:?}", output_file_path));
+
+                Ok(())
             }
             Err(e) => {
+                // Errors (eg. isolated declaration errors) prevent any output from being written
                 eprintln!("{}", e);
+                Err(format!("Failed to compile {:?}", input_path))
             }
         }
     }
@@ -231,26 +476,83 @@ impl Transpiler {
         }
 
         let output_path = options.output_path.as_ref().unwrap();
-        let output_file_path = output_path.join(path);
+        let output_file_path = self.resolve(&self.determine_output_file_path(output_path, path));
 
         if let Some(path) = output_file_path.parent() {
             fs::create_dir_all(path)
                 .unwrap_or_else(|_| panic!("Failed to create directory {:?}", path));
         };
 
-        fs::copy(path, &output_file_path)
+        fs::copy(self.resolve(path), &output_file_path)
             .unwrap_or_else(|_| panic!("Failed to copy JSON to {:?}", output_file_path));
     }
 
-    fn compile_directory(
+    /// Copies over non code files matching the asset globs in the `bndl` config,
+    /// mirroring their location relative to the root directory
+    fn handle_asset_file(
         &self,
-        input_path: &Path,
-        compiler: &swc::Compiler,
+        path: &Path,
         options: &swc::config::Options,
         glob_sets: &GlobSetConfig,
     ) {
+        if glob_sets.exclude.is_match(path) || !glob_sets.assets.is_match(normalize_path(path)) {
+            return;
+        }
+
+        let output_path = options.output_path.as_ref().unwrap();
+        let output_file_path = self.resolve(&self.determine_output_file_path(output_path, path));
+
+        if let Err(err) = create_directory_if_not_exists(&output_file_path) {
+            panic!("Failed to create directory: {:?}", err);
+        }
+
+        debug!("Copying asset {:?} to {:?}", path, output_file_path);
+        fs::copy(self.resolve(path), &output_file_path)
+            .unwrap_or_else(|_| panic!("Failed to copy asset to {:?}", output_file_path));
+    }
+
+    /// Copies hand written declaration files over to the output directory so they end up
+    /// next to the emitted code. Only files that live in the root directory are copied
+    /// since anything outside of it isn't part of the output either.
+    fn handle_declaration_file(
+        &self,
+        path: &Path,
+        options: &swc::config::Options,
+        glob_sets: &GlobSetConfig,
+    ) {
+        if glob_sets.exclude.is_match(path)
+            || (!glob_sets.include.is_empty() && !glob_sets.include.is_match(path))
+            // The root directory is always determined before declaration files are handled
+            || !normalize_path(path).starts_with(self.determine_root_dir(&[]))
+        {
+            return;
+        }
+
+        let output_path = options.output_path.as_ref().unwrap();
+        let output_file_path = self.resolve(&self.determine_output_file_path(output_path, path));
+
+        if let Err(err) = create_directory_if_not_exists(&output_file_path) {
+            panic!("Failed to create directory: {:?}", err);
+        }
+
+        fs::copy(self.resolve(path), &output_file_path).unwrap_or_else(|_| {
+            panic!("Failed to copy declaration file to {:?}", output_file_path)
+        });
+    }
+
+    /// Walks the input directory and sorts the files by how they should be handled
+    fn collect_input_files(
+        &self,
+        input_path: &Path,
+        glob_sets: &GlobSetConfig,
+        copy_declarations: bool,
+    ) -> InputFiles {
         let mut paths = Vec::new();
-        let mut it = WalkDir::new(input_path).into_iter();
+        let mut json_paths = Vec::new();
+        let mut declaration_paths = Vec::new();
+        let mut asset_paths = Vec::new();
+        let walk_root = self.resolve(input_path);
+        let mut it = WalkDir::new(&walk_root).into_iter();
 
         loop {
             let entry = match it.next() {
@@ -262,44 +564,94 @@ impl Transpiler {
                 Some(Ok(entry)) => entry,
             };
 
-            let path = entry.path();
-            if path.is_dir() && check_to_ignore_dir(&entry, glob_sets) {
+            // Keep working with paths relative to the project, like the input path
+            let path = &input_path.join(entry.path().strip_prefix(&walk_root).unwrap());
+            if entry.file_type().is_dir() && check_to_ignore_dir(path, &entry, glob_sets) {
                 it.skip_current_dir();
                 continue;
             }
 
-            if path.is_symlink() {
+            if entry.path_is_symlink() {
                 // Don't bother following symlinks
                 continue;
             }
 
-            if path
-                .extension()
-                .map_or(false, |ext| ext == "ts" || ext == "tsx" || ext == "js")
-            {
+            if is_declaration_file(path) {
+                // Declaration files are never compiled, but optionally copied over as is
+                if copy_declarations {
+                    declaration_paths.push(path.to_path_buf());
+                }
+            } else if self.has_input_extension(path) {
                 // Keep track of this path for compiling
-                paths.push(path.to_path_buf());
+                if !check_to_ignore_file(path, glob_sets, &self.input_extensions) {
+                    paths.push(path.to_path_buf());
+                }
+            } else if glob_sets.assets.is_match(normalize_path(path)) {
+                // Assets are copied as is once we know the root directory
+                asset_paths.push(path.to_path_buf());
             } else if path.extension().unwrap_or_default() == "json" {
-                // Handle JSON files separately
-                self.handle_json_file(path, options, glob_sets);
+                // Handle JSON files separately once we know the root directory
+                json_paths.push(path.to_path_buf());
             }
         }
 
-        // Compile all the files we found in parallel
+        InputFiles {
+            paths,
+            json_paths,
+            declaration_paths,
+            asset_paths,
+        }
+    }
+
+    fn compile_directory(
+        &self,
+        input_path: &Path,
+        compiler: &swc::Compiler,
+        options: &swc::config::Options,
+        glob_sets: &GlobSetConfig,
+        copy_declarations: bool,
+    ) -> usize {
+        let InputFiles {
+            paths,
+            json_paths,
+            declaration_paths,
+            asset_paths,
+        } = self.collect_input_files(input_path, glob_sets, copy_declarations);
+
+        // Determine the root directory based on all the files we found before writing any output
+        self.determine_root_dir(&paths);
+
+        for path in json_paths {
+            self.handle_json_file(&path, options, glob_sets);
+        }
+
+        for path in declaration_paths {
+            self.handle_declaration_file(&path, options, glob_sets);
+        }
+
+        asset_paths
+            .par_iter()
+            .for_each(|path| self.handle_asset_file(path, options, glob_sets));
+
+        // Compile all the files we found in parallel and count the ones that failed
         paths
             .par_iter()
-            .for_each(|path| self.compile_file(path, compiler, options, glob_sets));
+            .filter(|path| {
+                self.compile_file(path, compiler, options, glob_sets)
+                    .is_err()
+            })
+            .count()
     }
 
     pub fn transpile(&self, opts: TranspileOptions) -> Result<(), Box<dyn std::error::Error>> {
         if opts.clean {
-            clean_out_dir(&opts.out_dir)?;
+            clean_out_dir(self.converter.project_dir(), &opts.out_dir)?;
         }
 
         let options = swc::config::Options {
             output_path: Some(opts.out_dir.clone()),
             swcrc: false,
-            ..self.converter.convert()
+            ..self.converter.convert()?
         };
 
         debug!(
@@ -314,30 +666,98 @@ impl Transpiler {
         let cm: Arc<SourceMap> = Arc::<SourceMap>::default();
         let compiler = swc::Compiler::new(cm);
 
-        let input_path = prepare_input_path(&opts.input_path);
-        if input_path.is_file() && input_path.exists() {
-            self.compile_file(&input_path, &compiler, &options, &glob_sets);
-        } else {
-            self.compile_directory(&input_path, &compiler, &options, &glob_sets);
-        }
-
-        // Rely on `tsc` to provide .d.ts files since SWC's implementation is a bit weird
+        // Rely on `tsc` to provide .d.ts files when SWC isn't emitting them itself
+        let mut tsc_dts = None;
         if let Some(compiler_options) = self.converter.tsconfig.clone().compilerOptions {
-            if compiler_options.declaration.unwrap_or_default() {
+            if self.converter.should_emit_declarations()
+                && !self.converter.should_emit_swc_declarations()
+            {
                 // Give preference to specified declaration directory in tsconfig
-                let declaration_dir = if compiler_options.declarationDir.is_some() {
-                    Path::new(compiler_options.declarationDir.as_ref().unwrap())
-                } else {
-                    opts.out_dir.as_path()
-                };
+                let declaration_dir =
+                    if let Some(declaration_dir) = &compiler_options.declarationDir {
+                        Path::new(declaration_dir)
+                    } else {
+                        opts.out_dir.as_path()
+                    };
+
+                tsc_dts = Some(spawn_tsc_dts(
+                    self.converter.project_dir(),
+                    &opts.config_path,
+                    declaration_dir,
+                )?);
+            }
+        }
+
+        // Type check in parallel with the compilation since SWC only strips types,
+        // it's spawned last so nothing can return before it is joined below
+        let typecheck = if opts.typecheck {
+            let type_checker = TypeChecker::new(self.converter.project_dir(), &opts.config_path);
+            Some(std::thread::spawn(move || type_checker.check()))
+        } else {
+            None
+        };
 
-                create_tsc_dts(&opts.config_path, declaration_dir);
+        let input_path = prepare_input_path(&opts.input_path, self.converter.project_dir());
+        let mut error_count = 0;
+        if self.resolve(&input_path).is_file() {
+            // A single file says nothing about the root directory, so determine it from
+            // the files in the project like a full build would
+            if self.root_dir.get().is_none() {
+                let input_files = self.collect_input_files(Path::new("."), &glob_sets, false);
+                self.determine_root_dir(&input_files.paths);
+            }
+
+            if is_declaration_file(&input_path) {
+                if opts.copy_declarations {
+                    self.handle_declaration_file(&input_path, &options, &glob_sets);
+                }
+            } else if !self.has_input_extension(&input_path)
+                && glob_sets.assets.is_match(&input_path)
+            {
+                self.handle_asset_file(&input_path, &options, &glob_sets);
+            } else if self
+                .compile_file(&input_path, &compiler, &options, &glob_sets)
+                .is_err()
+            {
+                error_count += 1;
             }
+        } else {
+            error_count = self.compile_directory(
+                &input_path,
+                &compiler,
+                &options,
+                &glob_sets,
+                opts.copy_declarations,
+            );
+        }
+
+        // Wait for the declarations and the type check to finish before reporting any errors,
+        // so no `tsc` process is left running when the build fails
+        let declarations_result = match tsc_dts {
+            Some(child) => wait_for_tsc_dts(child, opts.declaration_errors),
+            None => Ok(()),
+        };
+        let typecheck_result = match typecheck {
+            Some(typecheck) => typecheck
+                .join()
+                .unwrap_or_else(|_| Err("Type check panicked".to_string())),
+            None => Ok(()),
+        };
+
+        declarations_result?;
+
+        // Files SWC failed to emit declarations for aren't written at all, so treat them like
+        // `tsc` declaration failures
+        if error_count > 0 && self.converter.should_emit_swc_declarations() {
+            report_compile_errors(error_count, opts.declaration_errors)?;
         }
 
+        typecheck_result?;
+
         // Bundle the monorepo dependencies if the flag is set
         if opts.bundle {
-            self.bundler.bundle(&opts.out_dir)?;
+            self.bundler
+                .bundle(self.converter.project_dir(), &opts.out_dir)?;
         }
 
         Ok(())
@@ -351,14 +771,27 @@ impl Transpiler {
         exec: Option<&String>,
     ) -> notify::Result<()> {
         let (tx, rx) = std::sync::mpsc::channel();
-        let app_dir = env::current_dir().unwrap_or(PathBuf::from("."));
+        let app_dir = self.converter.project_dir().to_path_buf();
+
+        // Type checking happens in the background so it never blocks a restart
+        let type_checker = opts
+            .typecheck
+            .then(|| TypeChecker::new(&app_dir, &opts.config_path));
+        let running_typecheck = Arc::new(Mutex::new(None));
 
         // Transpile fully once before we start watching
-        if let Err(err) = self.transpile(opts.clone()) {
+        if let Err(err) = self.transpile(TranspileOptions {
+            typecheck: false,
+            ..opts.clone()
+        }) {
             eprintln!("{err}");
             process::exit(1);
         }
 
+        if let Some(ref type_checker) = type_checker {
+            type_checker.check_in_background(&running_typecheck);
+        }
+
         let input_path = opts.input_path.clone();
         let mut watcher =
             notify::recommended_watcher(move |res: Result<notify::Event, notify::Error>| {
@@ -404,6 +837,9 @@ impl Transpiler {
                                 config_path: opts.config_path.clone(),
                                 clean: false,
                                 bundle: false,
+                                copy_declarations: opts.copy_declarations,
+                                declaration_errors: opts.declaration_errors,
+                                typecheck: false,
                             };
 
                             if let Err(err) = self.transpile(opts.clone()) {
@@ -413,6 +849,10 @@ impl Transpiler {
 
                             tx.send(opts.input_path).unwrap();
                         }
+
+                        if let Some(ref type_checker) = type_checker {
+                            type_checker.check_in_background(&running_typecheck);
+                        }
                     }
                     Err(err) => {
                         eprintln!("{:?}", err);
@@ -491,3 +931,148 @@ impl Transpiler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::{create_converter, create_manager, TestProject};
+
+    fn transpile(project_dir: &Path, input_path: &str) {
+        transpile_with(project_dir, input_path, DeclarationErrors::Error).unwrap();
+    }
+
+    fn transpile_with(
+        project_dir: &Path,
+        input_path: &str,
+        declaration_errors: DeclarationErrors,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let converter = create_converter(project_dir, project_dir);
+        let bundler = Bundler::new(Some(&create_manager(project_dir)))?;
+
+        Transpiler::new(&converter, &bundler).transpile(TranspileOptions {
+            input_path: PathBuf::from(input_path),
+            out_dir: PathBuf::from("dist"),
+            config_path: PathBuf::from("tsconfig.json"),
+            clean: false,
+            bundle: false,
+            copy_declarations: true,
+            declaration_errors,
+            typecheck: false,
+        })
+    }
+
+    #[test]
+    fn transpile_skips_declaration_and_javascript_files_without_allow_js() {
+        let project = TestProject::new(&[
+            ("tsconfig.json", "{}"),
+            ("package.json", "{}"),
+            ("src/index.ts", "export const a = 1;"),
+            ("src/types.d.ts", "declare const b: number;"),
+            ("src/script.js", "export const c = 1;"),
+        ]);
+        transpile(project.path(), ".");
+
+        let dist = project.path().join("dist");
+        assert!(dist.join("index.js").is_file());
+        // Declaration files are copied as is rather than compiled
+        assert_eq!(
+            fs::read_to_string(dist.join("types.d.ts")).unwrap(),
+            "declare const b: number;"
+        );
+        assert!(!dist.join("types.d.js").exists());
+        assert!(!dist.join("script.js").exists());
+    }
+
+    #[test]
+    fn transpile_compiles_javascript_files_with_allow_js() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "allowJs": true } }"#,
+            ),
+            ("package.json", "{}"),
+            ("src/index.ts", "export const a = 1;"),
+            ("src/script.js", "export const c = 1;"),
+        ]);
+        transpile(project.path(), ".");
+
+        assert!(project.path().join("dist/script.js").is_file());
+    }
+
+    #[test]
+    fn transpile_infers_the_root_dir_from_the_project_for_a_single_file() {
+        let project = TestProject::new(&[
+            ("tsconfig.json", "{}"),
+            ("package.json", "{}"),
+            ("src/index.ts", "export const a = 1;"),
+            ("src/nested/util.ts", "export const b = 1;"),
+        ]);
+        transpile(project.path(), "src/nested/util.ts");
+
+        // Like a full build, rather than `dist/util.js`
+        assert!(project.path().join("dist/nested/util.js").is_file());
+    }
+
+    #[test]
+    fn transpile_copies_the_assets_matching_the_bndl_config() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "bndl": { "assets": ["src/**/*.graphql"] } }"#,
+            ),
+            ("package.json", "{}"),
+            ("src/index.ts", "export const a = 1;"),
+            ("src/schema/user.graphql", "type User { id: ID! }"),
+            ("src/notes.md", ""),
+        ]);
+        transpile(project.path(), ".");
+
+        let dist = project.path().join("dist");
+        assert_eq!(
+            fs::read_to_string(dist.join("schema/user.graphql")).unwrap(),
+            "type User { id: ID! }"
+        );
+        assert!(!dist.join("notes.md").exists());
+    }
+
+    #[test]
+    fn transpile_emits_declarations_with_swc() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "declaration": true } }"#,
+            ),
+            ("package.json", "{}"),
+            (
+                "src/index.ts",
+                "export function add(a: number, b: number): number { return a + b; }",
+            ),
+        ]);
+        transpile(project.path(), ".");
+
+        let declaration = fs::read_to_string(project.path().join("dist/index.d.ts")).unwrap();
+        assert!(declaration.contains("export declare function add(a: number, b: number): number;"));
+    }
+
+    #[test]
+    fn transpile_fails_on_files_swc_cannot_emit_declarations_for() {
+        let files = [
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "declaration": true } }"#,
+            ),
+            ("package.json", "{}"),
+            ("src/index.ts", "export const a: number = 1;"),
+            // Isolated declarations require an explicit return type
+            ("src/add.ts", "export function add(a: number) { return a; }"),
+        ];
+
+        let project = TestProject::new(&files);
+        assert!(transpile_with(project.path(), ".", DeclarationErrors::Error).is_err());
+        assert!(!project.path().join("dist/add.js").exists());
+
+        let project = TestProject::new(&files);
+        assert!(transpile_with(project.path(), ".", DeclarationErrors::Warn).is_ok());
+        assert!(project.path().join("dist/index.d.ts").is_file());
+    }
+}
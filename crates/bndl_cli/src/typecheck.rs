@@ -0,0 +1,247 @@
+use log::debug;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{self, Child, ChildStdout};
+use std::sync::{Arc, Mutex};
+use swc_common::errors::DiagnosticId;
+use swc_common::{BytePos, FileName, SourceMap, Span, GLOBALS};
+
+/// A single diagnostic as reported by `tsc --pretty false`, eg.
+/// `src/index.ts(1,7): error TS2322: Type 'string' is not assignable to type 'number'.`
+struct Diagnostic {
+    file: PathBuf,
+    line: usize,
+    column: usize,
+    code: String,
+    message: String,
+}
+
+impl Diagnostic {
+    fn parse(line: &str) -> Option<Self> {
+        let (location, rest) = line.split_once("): error ")?;
+        let (file, position) = location.rsplit_once('(')?;
+        let (line, column) = position.split_once(',')?;
+        let (code, message) = rest.split_once(": ")?;
+
+        Some(Self {
+            file: PathBuf::from(file),
+            line: line.parse().ok()?,
+            column: column.parse().ok()?,
+            code: code.to_string(),
+            message: message.to_string(),
+        })
+    }
+
+    /// Report the diagnostic through the same SWC handler we use for compile errors
+    /// so both look the same in the output
+    fn report(&self, cm: &Arc<SourceMap>) {
+        let result = GLOBALS.set(&Default::default(), || {
+            swc::try_with_handler(cm.clone(), Default::default(), |handler| {
+                // Files with several diagnostics are only loaded once
+                let fm = match cm.get_source_file(&FileName::Real(self.file.clone())) {
+                    Some(fm) => fm,
+                    None => cm.load_file(&self.file)?,
+                };
+                let lines = &fm.analyze().lines;
+                let line_start = lines
+                    .get(self.line.saturating_sub(1))
+                    .copied()
+                    .unwrap_or(fm.start_pos);
+                let line = &fm.src[(line_start.0 - fm.start_pos.0) as usize..];
+                let lo = BytePos(line_start.0 + utf16_column_to_byte_offset(line, self.column));
+                let hi = BytePos((lo.0 + 1).min(fm.end_pos.0));
+
+                handler
+                    .struct_span_err_with_code(
+                        Span::new(lo, hi),
+                        &self.message,
+                        DiagnosticId::Error(self.code.clone()),
+                    )
+                    .emit();
+
+                Ok(())
+            })
+        });
+
+        if let Err(err) = result {
+            eprintln!("{err}");
+        }
+    }
+}
+
+/// `tsc` reports 1-based columns in UTF-16 code units, while SWC spans are byte offsets
+fn utf16_column_to_byte_offset(line: &str, column: usize) -> u32 {
+    let mut units = 0;
+    let mut offset = 0;
+
+    for char in line.chars() {
+        if units >= column.saturating_sub(1) || char == '\n' {
+            break;
+        }
+
+        units += char.len_utf16();
+        offset += char.len_utf8();
+    }
+
+    offset as u32
+}
+
+/// In charge of type checking the project with `tsc`, since SWC only strips types
+#[derive(Clone)]
+pub struct TypeChecker {
+    project_dir: PathBuf,
+    config_path: PathBuf,
+}
+
+impl TypeChecker {
+    pub fn new(project_dir: &Path, config_path: &Path) -> Self {
+        Self {
+            project_dir: project_dir.to_path_buf(),
+            config_path: config_path.to_path_buf(),
+        }
+    }
+
+    fn spawn(&self) -> std::io::Result<Child> {
+        let args = vec![
+            "tsc",
+            "--noEmit",
+            "--pretty",
+            "false",
+            "--project",
+            self.config_path.to_str().unwrap(),
+        ];
+
+        std::process::Command::new("npx")
+            .args(args)
+            .current_dir(&self.project_dir)
+            .stdout(process::Stdio::piped())
+            .stderr(process::Stdio::inherit())
+            .spawn()
+    }
+
+    /// Streams the diagnostics of a running type check as they come in
+    /// and returns the amount of errors that were found
+    fn report_diagnostics(&self, stdout: ChildStdout) -> usize {
+        // A fresh source map per run, so the files loaded for reporting don't pile up in watch mode
+        let cm = Arc::<SourceMap>::default();
+        let mut error_count = 0;
+        let mut pending: Option<Diagnostic> = None;
+
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if let Some(mut diagnostic) = Diagnostic::parse(&line) {
+                // `tsc` reports the files relative to the project it runs in
+                diagnostic.file = self.project_dir.join(&diagnostic.file);
+
+                if let Some(previous) = pending.replace(diagnostic) {
+                    previous.report(&cm);
+                }
+
+                error_count += 1;
+            } else if let Some(diagnostic) = pending.as_mut().filter(|_| line.starts_with(' ')) {
+                // Indented lines continue the message of the previous diagnostic
+                diagnostic.message.push('\n');
+                diagnostic.message.push_str(line.trim());
+            } else {
+                // Diagnostics without a location, eg. config errors
+                if line.starts_with("error ") {
+                    error_count += 1;
+                }
+
+                eprintln!("{line}");
+            }
+        }
+
+        if let Some(diagnostic) = pending {
+            diagnostic.report(&cm);
+        }
+
+        error_count
+    }
+
+    /// Runs a full type check and fails when `tsc` reports errors
+    pub fn check(&self) -> Result<(), String> {
+        let mut child = self.spawn().map_err(|err| err.to_string())?;
+        let error_count = self.report_diagnostics(child.stdout.take().unwrap());
+        let status = child.wait().map_err(|err| err.to_string())?;
+
+        if error_count > 0 {
+            return Err(format!("Found {error_count} type error(s)"));
+        }
+
+        if !status.success() {
+            return Err(format!("Type check failed ({status})"));
+        }
+
+        Ok(())
+    }
+
+    /// Runs a type check in the background, cancelling the one that is still running.
+    /// Used in watch mode so type errors are reported without blocking restarts.
+    pub fn check_in_background(&self, running: &Arc<Mutex<Option<Child>>>) {
+        let mut running = running.lock().unwrap();
+
+        if let Some(mut previous) = running.take() {
+            debug!("Cancelling previous type check");
+            let _ = previous.kill();
+            let _ = previous.wait();
+        }
+
+        let mut child = match self.spawn() {
+            Ok(child) => child,
+            Err(err) => {
+                eprintln!("{err}");
+                return;
+            }
+        };
+
+        let stdout = child.stdout.take().unwrap();
+        let checker = self.clone();
+        std::thread::spawn(move || {
+            let error_count = checker.report_diagnostics(stdout);
+            if error_count > 0 {
+                eprintln!("Found {error_count} type error(s)");
+            }
+        });
+
+        *running = Some(child);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_the_location_code_and_message() {
+        let diagnostic = Diagnostic::parse(
+            "src/index.ts(12,7): error TS2322: Type 'string' is not assignable to type 'number'.",
+        )
+        .unwrap();
+
+        assert_eq!(diagnostic.file, PathBuf::from("src/index.ts"));
+        assert_eq!(diagnostic.line, 12);
+        assert_eq!(diagnostic.column, 7);
+        assert_eq!(diagnostic.code, "TS2322");
+        assert_eq!(
+            diagnostic.message,
+            "Type 'string' is not assignable to type 'number'."
+        );
+    }
+
+    #[test]
+    fn parse_skips_lines_without_a_location() {
+        assert!(Diagnostic::parse("error TS5058: The specified path does not exist.").is_none());
+        assert!(Diagnostic::parse("  Property 'a' is missing.").is_none());
+    }
+
+    #[test]
+    fn utf16_columns_are_converted_to_byte_offsets() {
+        assert_eq!(utf16_column_to_byte_offset("const a = 1;", 7), 6);
+        // `é` is one UTF-16 code unit but two bytes
+        assert_eq!(utf16_column_to_byte_offset("const é = a;", 11), 11);
+        // `😀` is two UTF-16 code units and four bytes
+        assert_eq!(utf16_column_to_byte_offset("'😀' + a", 8), 9);
+        // Columns past the end of the line stay on that line
+        assert_eq!(utf16_column_to_byte_offset("ab\ncd", 10), 2);
+    }
+}
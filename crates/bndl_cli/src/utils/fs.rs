@@ -24,7 +24,7 @@ pub fn copy_dir_all(src: &PathBuf, dst: &PathBuf, excl: Option<&Vec<String>>) ->
             copy_dir_all(&entry.path(), &dst.join(entry.file_name()), excl)?;
         } else {
             if !dst.exists() {
-                fs::create_dir_all(&dst)?;
+                fs::create_dir_all(dst)?;
             }
 
             fs::copy(entry.path(), dst.join(entry.file_name()))?;
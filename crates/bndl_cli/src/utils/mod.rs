@@ -1,2 +1,5 @@
 pub mod fs;
 pub mod sourcemap;
+pub mod specifier;
+#[cfg(test)]
+pub mod testing;
@@ -5,6 +5,7 @@ pub fn extend_source_map(
     source_map: String,
     source_file_name: &Option<String>,
     source_root: &Option<String>,
+    extension: &str,
 ) -> Vec<u8> {
     let mut source_map = swc::sourcemap::SourceMap::from_reader(source_map.as_bytes())
         .expect("failed to encode source map");
@@ -15,7 +16,7 @@ pub fn extend_source_map(
         }
 
         if source_map.get_file().is_none() {
-            let path = Path::new(source_file_name).with_extension("js");
+            let path = Path::new(source_file_name).with_extension(extension);
             let file = path.file_name().unwrap();
 
             source_map.set_file(Some(file.to_str().unwrap()));
@@ -0,0 +1,333 @@
+use bndl_convert::{normalize_path, Converter};
+use bndl_deps::Manager;
+use log::debug;
+use std::path::{Path, PathBuf};
+use swc_ecma_ast::{CallExpr, Callee, ExportAll, Expr, ImportDecl, Lit, NamedExport, Str};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+/// Extensions we try, in order, when resolving an extensionless specifier on disk
+const SOURCE_EXTENSIONS: [&str; 8] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// An internal monorepo package that path aliases can point into
+pub struct InternalPackage {
+    name: String,
+    path: PathBuf,
+    /// The directory the compiled output of the package mirrors, which is what `Bundler` copies
+    root_dir: PathBuf,
+}
+
+impl InternalPackage {
+    /// Collects the internal packages, except for the one in the project directory
+    /// and the workspace root(s) containing it. The root directory of each package is
+    /// determined by `determine_root_dir`, relative to the package directory.
+    pub fn collect(
+        manager: &Manager,
+        app_dir: &Path,
+        determine_root_dir: impl Fn(&Path) -> PathBuf,
+    ) -> Vec<Self> {
+        manager
+            .packages
+            .iter()
+            .filter(|(_, path)| !app_dir.starts_with(path))
+            .map(|(name, path)| Self {
+                name: name.clone(),
+                path: path.clone(),
+                root_dir: normalize_path(&path.join(determine_root_dir(path))),
+            })
+            .collect()
+    }
+}
+
+/// Rewrites relative import specifiers so they point to the emitted files, mirroring
+/// `rewriteRelativeImportExtensions` in `tsc`. Extensionless specifiers are only fully resolved
+/// when `resolve_fully` is set, since ES modules require full paths at runtime. Otherwise we
+/// only rewrite them when they point to `.mjs` or `.cjs` files that `require` can't find.
+///
+/// Since it runs after SWC resolved the `paths` aliases, it also warns about any alias
+/// that is still left in the output as it would fail at runtime. Relative specifiers that end
+/// up in another internal package are rewritten to the package name, so they keep working once
+/// `Bundler` copied the package into the `node_modules` of the output directory.
+pub struct SpecifierRewriter<'a> {
+    converter: &'a Converter,
+    internal_packages: &'a [InternalPackage],
+    input_path: PathBuf,
+    source_dir: PathBuf,
+    resolve_fully: bool,
+}
+
+impl<'a> SpecifierRewriter<'a> {
+    pub fn new(
+        converter: &'a Converter,
+        internal_packages: &'a [InternalPackage],
+        input_path: &Path,
+        resolve_fully: bool,
+    ) -> Self {
+        Self {
+            converter,
+            internal_packages,
+            input_path: input_path.to_path_buf(),
+            source_dir: input_path.parent().unwrap_or(Path::new("")).to_path_buf(),
+            resolve_fully,
+        }
+    }
+
+    fn rewrite(&self, specifier: &str) -> Option<String> {
+        if !specifier.starts_with("./") && !specifier.starts_with("../") {
+            if let Some(alias) = self.converter.find_path_alias(specifier) {
+                eprintln!(
+                    "Warning: unresolved path alias {:?} (matching {:?}) in {:?}",
+                    specifier, alias, self.input_path
+                );
+            }
+
+            return None;
+        }
+
+        // Declaration files never have an emitted counterpart
+        if specifier.ends_with(".d.ts") {
+            return None;
+        }
+
+        if let Some(specifier) = self.rewrite_internal_package(specifier) {
+            return Some(specifier);
+        }
+
+        let path = Path::new(specifier);
+        if let Some("ts" | "tsx" | "mts" | "cts") = path.extension().and_then(|ext| ext.to_str()) {
+            let extension = self.converter.determine_output_extension(path);
+
+            return Some(path.with_extension(extension).to_string_lossy().to_string());
+        }
+
+        let target = self
+            .converter
+            .project_dir()
+            .join(&self.source_dir)
+            .join(specifier);
+        if target.is_file() {
+            return None;
+        }
+
+        for ext in SOURCE_EXTENSIONS {
+            let candidate = PathBuf::from(format!("{}.{ext}", target.to_string_lossy()));
+            if candidate.is_file() {
+                let extension = self.converter.determine_output_extension(&candidate);
+                if !self.resolve_fully && extension == "js" {
+                    return None;
+                }
+
+                return Some(format!("{specifier}.{extension}"));
+            }
+        }
+
+        for ext in SOURCE_EXTENSIONS {
+            let candidate = target.join(format!("index.{ext}"));
+            if candidate.is_file() {
+                let extension = self.converter.determine_output_extension(&candidate);
+                if !self.resolve_fully && extension == "js" {
+                    return None;
+                }
+
+                return Some(format!(
+                    "{}/index.{extension}",
+                    specifier.trim_end_matches('/')
+                ));
+            }
+        }
+
+        None
+    }
+
+    /// Points specifiers that resolve into another internal package to the package itself,
+    /// as the compiled output of the package mirrors its root directory
+    fn rewrite_internal_package(&self, specifier: &str) -> Option<String> {
+        if self.internal_packages.is_empty() {
+            return None;
+        }
+
+        let app_dir = self.converter.project_dir();
+        // The target of a specifier doesn't have to exist on disk with that exact name
+        let target = normalize_path(&app_dir.join(&self.source_dir).join(specifier));
+
+        // Prefer the most specific package in case packages are nested
+        let package = self
+            .internal_packages
+            .iter()
+            .filter(|package| target.starts_with(&package.path))
+            .max_by_key(|package| package.path.components().count())?;
+
+        if !self.converter.has_dependency(&package.name) {
+            eprintln!(
+                "Warning: {:?} in {:?} points into {:?}, add it to the dependencies so it gets bundled",
+                specifier, self.input_path, package.name
+            );
+        }
+
+        // Only the root directory ends up in the output of the package
+        let Ok(subpath) = target.strip_prefix(&package.root_dir) else {
+            debug!(
+                "{:?} in {:?} points outside the root directory of {:?}",
+                specifier, self.input_path, package.name
+            );
+            return None;
+        };
+        let subpath = match subpath.extension().and_then(|ext| ext.to_str()) {
+            Some("ts" | "tsx" | "mts" | "cts") => {
+                subpath.with_extension(self.converter.determine_output_extension(subpath))
+            }
+            _ => subpath.to_path_buf(),
+        };
+
+        // The index in the root directory is the entry point of the package
+        if subpath.as_os_str().is_empty() || subpath.with_extension("") == Path::new("index") {
+            return Some(package.name.clone());
+        }
+
+        Some(format!("{}/{}", package.name, subpath.to_string_lossy()))
+    }
+
+    fn rewrite_str(&self, src: &mut Str) {
+        if let Some(specifier) = self.rewrite(&src.value) {
+            src.value = specifier.into();
+            src.raw = None;
+        }
+    }
+}
+
+impl VisitMut for SpecifierRewriter<'_> {
+    fn visit_mut_import_decl(&mut self, node: &mut ImportDecl) {
+        self.rewrite_str(&mut node.src);
+    }
+
+    fn visit_mut_named_export(&mut self, node: &mut NamedExport) {
+        if let Some(ref mut src) = node.src {
+            self.rewrite_str(src);
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, node: &mut ExportAll) {
+        self.rewrite_str(&mut node.src);
+    }
+
+    /// Handles both dynamic `import()` and the `require()` calls emitted for CommonJS
+    fn visit_mut_call_expr(&mut self, node: &mut CallExpr) {
+        node.visit_mut_children_with(self);
+
+        let is_import = match &node.callee {
+            Callee::Import(_) => true,
+            Callee::Expr(expr) => matches!(&**expr, Expr::Ident(ident) if &*ident.sym == "require"),
+            _ => false,
+        };
+
+        if !is_import {
+            return;
+        }
+
+        if let Some(arg) = node.args.first_mut() {
+            if let Expr::Lit(Lit::Str(ref mut src)) = *arg.expr {
+                self.rewrite_str(src);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::testing::{create_converter, TestProject};
+
+    #[test]
+    fn rewrite_points_relative_specifiers_to_the_emitted_files() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "module": "nodenext" } }"#,
+            ),
+            ("package.json", r#"{ "name": "app", "type": "module" }"#),
+            ("src/index.ts", ""),
+            ("src/util.ts", ""),
+            ("src/legacy.cts", ""),
+            ("src/dir/index.tsx", ""),
+            ("src/data.json", ""),
+        ]);
+        let converter = create_converter(project.path(), project.path());
+        let rewriter = SpecifierRewriter::new(&converter, &[], Path::new("src/index.ts"), true);
+
+        assert_eq!(rewriter.rewrite("./util").as_deref(), Some("./util.js"));
+        assert_eq!(rewriter.rewrite("./util.ts").as_deref(), Some("./util.js"));
+        assert_eq!(
+            rewriter.rewrite("./legacy").as_deref(),
+            Some("./legacy.cjs")
+        );
+        assert_eq!(rewriter.rewrite("./dir").as_deref(), Some("./dir/index.js"));
+
+        // Existing files, declaration files and bare specifiers are left alone
+        assert_eq!(rewriter.rewrite("./data.json"), None);
+        assert_eq!(rewriter.rewrite("./types.d.ts"), None);
+        assert_eq!(rewriter.rewrite("lodash"), None);
+
+        // CommonJS can resolve extensionless `.js` files, but not `.cjs` ones
+        let rewriter = SpecifierRewriter::new(&converter, &[], Path::new("src/index.ts"), false);
+        assert_eq!(rewriter.rewrite("./util"), None);
+        assert_eq!(
+            rewriter.rewrite("./legacy").as_deref(),
+            Some("./legacy.cjs")
+        );
+    }
+
+    #[test]
+    fn rewrite_points_specifiers_into_internal_packages_to_the_package() {
+        let project = TestProject::new(&[
+            ("apps/app/tsconfig.json", "{}"),
+            (
+                "apps/app/package.json",
+                r#"{ "name": "app", "dependencies": { "lib": "*" } }"#,
+            ),
+            ("apps/app/src/index.ts", ""),
+            ("packages/lib/src/index.ts", ""),
+            ("packages/lib/src/x.ts", ""),
+            ("packages/lib/scripts/build.ts", ""),
+        ]);
+        let app_dir = project.path().join("apps/app");
+        let converter = create_converter(&app_dir, project.path());
+        let internal_packages = [InternalPackage {
+            name: String::from("lib"),
+            path: project.path().join("packages/lib"),
+            root_dir: project.path().join("packages/lib/src"),
+        }];
+        let rewriter = SpecifierRewriter::new(
+            &converter,
+            &internal_packages,
+            Path::new("src/index.ts"),
+            false,
+        );
+
+        assert_eq!(
+            rewriter
+                .rewrite("../../../packages/lib/src/x.ts")
+                .as_deref(),
+            Some("lib/x.js")
+        );
+        assert_eq!(
+            rewriter.rewrite("../../../packages/lib/src/x").as_deref(),
+            Some("lib/x")
+        );
+        // The index in the root directory is the entry point of the package
+        assert_eq!(
+            rewriter
+                .rewrite("../../../packages/lib/src/index")
+                .as_deref(),
+            Some("lib")
+        );
+        assert_eq!(
+            rewriter.rewrite("../../../packages/lib/src").as_deref(),
+            Some("lib")
+        );
+
+        // Only the root directory of the package ends up in its output
+        assert_eq!(
+            rewriter.rewrite("../../../packages/lib/scripts/build"),
+            None
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use bndl_convert::{Converter, CreateConverterOptions};
+use bndl_deps::Manager;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{env, fs, process};
+
+/// A project directory with the given files, which is removed again once the test is done
+pub struct TestProject(PathBuf);
+
+impl TestProject {
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        // Unique per test (and test run) so tests can run in parallel
+        let project_dir = env::temp_dir().join(format!(
+            "bndl-cli-test-{}-{}",
+            process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        for (path, content) in files {
+            let path = project_dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        Self(project_dir)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TestProject {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// A workspace without any packages
+pub fn create_manager(workspace_root: &Path) -> Manager {
+    Manager {
+        root: workspace_root.to_path_buf(),
+        packages: HashMap::new(),
+    }
+}
+
+/// Creates a converter for the `tsconfig.json` of a project in a workspace without packages
+pub fn create_converter(project_dir: &Path, workspace_root: &Path) -> Converter {
+    Converter::from_path(
+        &project_dir.join("tsconfig.json"),
+        CreateConverterOptions {
+            manager: Some(create_manager(workspace_root)),
+            project_dir: Some(project_dir.to_path_buf()),
+            ..Default::default()
+        },
+    )
+    .unwrap()
+}
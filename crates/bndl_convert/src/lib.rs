@@ -1,27 +1,184 @@
 #[macro_use]
 extern crate lazy_static;
 
-use bndl_deps::Manager;
+use bndl_deps::{Manager, PackageJson};
 use globset::{Glob, GlobSet, GlobSetBuilder};
 use log::debug;
 use serde::{Deserialize, Serialize};
 use std::borrow::BorrowMut;
 use std::collections::HashMap;
-use std::path::{Path, PathBuf};
-use std::sync::{Mutex, MutexGuard};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, OnceLock};
 use std::{env, fs};
-use swc::config::{Config, ModuleConfig, Options, SourceMapsConfig};
+use swc::config::{Config, DecoratorVersion, ModuleConfig, Options, SourceMapsConfig};
 use swc::{
-    config::{JscConfig, Paths},
+    config::{JscConfig, JscExperimental, Paths},
     BoolConfig,
 };
-use swc_ecma_parser::{Syntax, TsConfig};
-use swc_ecma_transforms_module::{amd, common_js, umd};
+use swc_ecma_parser::{EsSyntax, Syntax, TsSyntax};
+use swc_ecma_transforms_module::{amd, common_js, umd, util::ImportInterop};
+use swc_ecma_transforms_react::{self as react, Runtime};
+
+/// Whether the given file is a TypeScript declaration file (`.d.ts`, `.d.mts` or `.d.cts`)
+pub fn is_declaration_file(path: &Path) -> bool {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+
+    file_name.ends_with(".d.ts") || file_name.ends_with(".d.mts") || file_name.ends_with(".d.cts")
+}
+
+/// Lexically resolves the `.` and `..` components of a path, without touching the file system.
+/// Leading `..` components of a relative path are kept since there is nothing to resolve them against.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => normalized.push(component),
+            },
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+/// Cleans up a generated config before it's printed or saved by removing `null` values, empty
+/// strings and the objects and arrays left empty by that. For SWC options it also removes the
+/// `module` settings that are equal to the defaults of the module type, since SWC serializes
+/// every one of them.
+pub fn remove_unwanted_values(value: &mut serde_json::Value) {
+    remove_empty_values(value);
+
+    let Some(default) = value.get("module").and_then(determine_default_module) else {
+        return;
+    };
+    if let Some(module) = value
+        .get_mut("module")
+        .and_then(|module| module.as_object_mut())
+    {
+        module.retain(|key, value| key == "type" || default.get(key) != Some(value));
+    }
+}
+
+/// The settings SWC uses for the `type` of the given `module` config
+fn determine_default_module(module: &serde_json::Value) -> Option<serde_json::Value> {
+    let r#type = module.get("type")?;
+    let default: ModuleConfig =
+        serde_json::from_value(serde_json::json!({ "type": r#type })).ok()?;
+
+    serde_json::to_value(default).ok()
+}
+
+fn remove_empty_values(value: &mut serde_json::Value) {
+    let is_empty = |value: &serde_json::Value| match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::String(string) => string.is_empty(),
+        serde_json::Value::Object(map) => map.is_empty(),
+        _ => false,
+    };
+
+    match value {
+        serde_json::Value::Object(map) => {
+            for value in map.values_mut() {
+                remove_empty_values(value);
+            }
+
+            map.retain(|_, value| !is_empty(value));
+        }
+        serde_json::Value::Array(values) => {
+            let was_empty = values.is_empty();
+            for value in values.iter_mut() {
+                remove_empty_values(value);
+            }
+
+            values.retain(|value| !is_empty(value));
+
+            // Arrays that only contained `null` values (eg. unset lint rules) are removed as well,
+            // but we keep the ones that were empty to begin with, like an empty `include`
+            if values.is_empty() && !was_empty {
+                *value = serde_json::Value::Null;
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Compiler options that only affect type checking (or `tsc` itself), so there is nothing to translate
+const TYPE_CHECK_OPTIONS: [&str; 52] = [
+    "allowArbitraryExtensions",
+    "allowImportingTsExtensions",
+    "allowUmdGlobalAccess",
+    "allowUnreachableCode",
+    "allowUnusedLabels",
+    "alwaysStrict",
+    "assumeChangesOnlyAffectDirectDependencies",
+    "checkJs",
+    "customConditions",
+    "diagnostics",
+    "disableReferencedProjectLoad",
+    "disableSolutionSearching",
+    "disableSourceOfProjectReferenceRedirect",
+    "exactOptionalPropertyTypes",
+    "explainFiles",
+    "extendedDiagnostics",
+    "forceConsistentCasingInFileNames",
+    "incremental",
+    "isolatedDeclarations",
+    "isolatedModules",
+    "lib",
+    "listEmittedFiles",
+    "listFiles",
+    "maxNodeModuleJsDepth",
+    "moduleDetection",
+    "noErrorTruncation",
+    "noFallthroughCasesInSwitch",
+    "noImplicitAny",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noImplicitThis",
+    "noPropertyAccessFromIndexSignature",
+    "noUncheckedIndexedAccess",
+    "noUnusedLocals",
+    "noUnusedParameters",
+    "plugins",
+    "preserveWatchOutput",
+    "pretty",
+    "resolvePackageJsonExports",
+    "resolvePackageJsonImports",
+    "skipDefaultLibCheck",
+    "skipLibCheck",
+    "strict",
+    "strictBindCallApply",
+    "strictFunctionTypes",
+    "strictNullChecks",
+    "strictPropertyInitialization",
+    "traceResolution",
+    "tsBuildInfoFile",
+    "typeRoots",
+    "types",
+    "useUnknownInCatchVariables",
+];
+
+/// Top level `tsconfig.json` fields that don't influence the output
+const IGNORED_TSCONFIG_FIELDS: [&str; 5] = [
+    "$schema",
+    "compileOnSave",
+    "typeAcquisition",
+    "watchOptions",
+    "ts-node",
+];
 
 lazy_static! {
     static ref TSCONFIG_CONTENT: Mutex<HashMap<PathBuf, String>> = Mutex::new(HashMap::new());
 }
 
+#[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct CompilerOptions {
     pub module: Option<String>,
@@ -38,14 +195,290 @@ pub struct CompilerOptions {
     pub removeComments: Option<bool>,
     pub resolveJsonModule: Option<bool>,
     pub esModuleInterop: Option<bool>,
+    pub allowSyntheticDefaultImports: Option<bool>,
+    pub moduleResolution: Option<String>,
+    pub jsx: Option<String>,
+    pub jsxFactory: Option<String>,
+    pub jsxFragmentFactory: Option<String>,
+    pub jsxImportSource: Option<String>,
+    pub emitDecoratorMetadata: Option<bool>,
+    pub useDefineForClassFields: Option<bool>,
+    pub importHelpers: Option<bool>,
+    pub verbatimModuleSyntax: Option<bool>,
+    pub preserveConstEnums: Option<bool>,
+    pub allowJs: Option<bool>,
+    pub rootDir: Option<String>,
+    pub composite: Option<bool>,
+    /// Every option we don't translate, kept around so we can report them
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
 }
 
+#[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct TsConfigJson {
     pub extends: Option<String>,
     pub compilerOptions: Option<CompilerOptions>,
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    pub bndl: Option<BndlConfig>,
+    pub references: Option<Vec<ProjectReference>>,
+    /// The directory of the config that declared `paths`, which they resolve relative to
+    /// when no `baseUrl` is specified
+    #[serde(skip)]
+    pub paths_base_dir: Option<PathBuf>,
+    /// Every top level field we don't translate, kept around so we can report them
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+    /// The config in the `extends` chain each option was taken from, eg. `compilerOptions.target`
+    #[serde(skip)]
+    pub sources: HashMap<String, PathBuf>,
+    /// The configs that were merged, starting with the config itself followed by its bases
+    #[serde(skip)]
+    pub config_files: Vec<PathBuf>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ConfigWarningKind {
+    /// The option isn't translated at all
+    Unsupported,
+    /// The option is translated, but the output differs from what `tsc` would emit
+    Lossy,
+}
+
+/// An option in the `tsconfig.json` that `bndl` can't (fully) honour
+#[derive(Serialize, Debug, Clone)]
+pub struct ConfigWarning {
+    pub option: String,
+    pub kind: ConfigWarningKind,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ConfigWarningKind::Unsupported => write!(f, "Unsupported option {}", self.option)?,
+            ConfigWarningKind::Lossy => write!(f, "Lossy option {}", self.option)?,
+        }
+
+        if !self.message.is_empty() {
+            write!(f, ": {}", self.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Where a setting in the converted options originates from
+#[derive(Serialize, Debug, Clone)]
+pub struct OptionSource {
+    pub option: String,
+    /// The config file declaring the option, or `None` when it was passed on the command line
+    pub config: Option<PathBuf>,
+}
+
+/// How a single converted SWC setting was derived, without sources it is a `bndl` default
+#[derive(Serialize, Debug, Clone)]
+pub struct OptionExplanation {
+    pub setting: String,
+    pub value: serde_json::Value,
+    pub sources: Vec<OptionSource>,
+}
+
+/// The tools a `tsconfig.json` can be converted for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Swc,
+    Esbuild,
+    Babel,
+}
+
+impl OutputFormat {
+    /// The file the generated config is saved as. SWC and Babel pick theirs up by default,
+    /// but esbuild has no config file, so `esbuild.json` is meant to be passed to its API.
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            OutputFormat::Swc => ".swcrc",
+            OutputFormat::Esbuild => "esbuild.json",
+            OutputFormat::Babel => "babel.config.json",
+        }
+    }
+}
+
+/// An SWC setting we emit (or accept in an `.swcrc`), matched on the longest prefix
+struct SwcSetting {
+    setting: &'static str,
+    /// The options that influence the setting, for `--explain`
+    origins: &'static [&'static str],
+    /// Whether the setting translates back to compiler options, or is implied by `tsc` anyway
+    reversible: bool,
+}
+
+impl SwcSetting {
+    const fn new(
+        setting: &'static str,
+        origins: &'static [&'static str],
+        reversible: bool,
+    ) -> Self {
+        Self {
+            setting,
+            origins,
+            reversible,
+        }
+    }
+
+    /// Finds the most specific entry for a setting like `jsc.transform.react.runtime`
+    fn find(setting: &str) -> Option<&'static SwcSetting> {
+        SWC_SETTINGS
+            .iter()
+            .filter(|entry| {
+                setting == entry.setting || setting.starts_with(&format!("{}.", entry.setting))
+            })
+            .max_by_key(|entry| entry.setting.len())
+    }
+}
+
+const REACT_ORIGINS: &[&str] = &[
+    "compilerOptions.jsx",
+    "compilerOptions.jsxFactory",
+    "compilerOptions.jsxFragmentFactory",
+    "compilerOptions.jsxImportSource",
+];
+
+const MODULE_ORIGINS: &[&str] = &["compilerOptions.module", "compilerOptions.moduleResolution"];
+
+const INTEROP_ORIGINS: &[&str] = &[
+    "compilerOptions.esModuleInterop",
+    "compilerOptions.allowSyntheticDefaultImports",
+];
+
+const SWC_SETTINGS: [SwcSetting; 33] = [
+    SwcSetting::new("$schema", &[], true),
+    SwcSetting::new("env", &["bndl.targetEngines", "engines.node"], false),
+    SwcSetting::new(
+        "inlineSourcesContent",
+        &["compilerOptions.inlineSources"],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.baseUrl",
+        &["compilerOptions.baseUrl", "compilerOptions.paths"],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.experimental.emitIsolatedDts",
+        &["compilerOptions.declaration", "compilerOptions.composite"],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.externalHelpers",
+        &["compilerOptions.importHelpers"],
+        true,
+    ),
+    SwcSetting::new("jsc.keepClassNames", &[], true),
+    SwcSetting::new("jsc.parser", &[], true),
+    SwcSetting::new("jsc.parser.tsx", &["compilerOptions.jsx"], true),
+    SwcSetting::new("jsc.paths", &["compilerOptions.paths"], true),
+    SwcSetting::new(
+        "jsc.preserveAllComments",
+        &["compilerOptions.removeComments"],
+        true,
+    ),
+    SwcSetting::new("jsc.target", &["compilerOptions.target"], true),
+    SwcSetting::new(
+        "jsc.transform.decoratorMetadata",
+        &[
+            "compilerOptions.experimentalDecorators",
+            "compilerOptions.emitDecoratorMetadata",
+        ],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.transform.decoratorVersion",
+        &["compilerOptions.experimentalDecorators"],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.transform.legacyDecorator",
+        &["compilerOptions.experimentalDecorators"],
+        true,
+    ),
+    SwcSetting::new("jsc.transform.react", REACT_ORIGINS, false),
+    SwcSetting::new("jsc.transform.react.development", REACT_ORIGINS, true),
+    SwcSetting::new("jsc.transform.react.importSource", REACT_ORIGINS, true),
+    SwcSetting::new("jsc.transform.react.pragma", REACT_ORIGINS, true),
+    SwcSetting::new("jsc.transform.react.pragmaFrag", REACT_ORIGINS, true),
+    SwcSetting::new("jsc.transform.react.runtime", REACT_ORIGINS, true),
+    SwcSetting::new(
+        "jsc.transform.treatConstEnumAsEnum",
+        &["compilerOptions.preserveConstEnums"],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.transform.useDefineForClassFields",
+        &[
+            "compilerOptions.useDefineForClassFields",
+            "compilerOptions.target",
+        ],
+        true,
+    ),
+    SwcSetting::new(
+        "jsc.transform.verbatimModuleSyntax",
+        &["compilerOptions.verbatimModuleSyntax"],
+        true,
+    ),
+    SwcSetting::new("minify", &["--minify"], false),
+    SwcSetting::new("module", MODULE_ORIGINS, false),
+    SwcSetting::new(
+        "module.exportInteropAnnotation",
+        &["compilerOptions.moduleResolution", "compilerOptions.module"],
+        false,
+    ),
+    SwcSetting::new("module.importInterop", INTEROP_ORIGINS, true),
+    SwcSetting::new("module.noInterop", INTEROP_ORIGINS, true),
+    SwcSetting::new("module.type", MODULE_ORIGINS, true),
+    SwcSetting::new("outputPath", &["compilerOptions.outDir"], true),
+    SwcSetting::new(
+        "sourceMaps",
+        &[
+            "compilerOptions.sourceMap",
+            "compilerOptions.inlineSourceMap",
+        ],
+        true,
+    ),
+    SwcSetting::new("swcrc", &[], true),
+];
+
+/// A reference to another TypeScript project, as used by `tsc -b`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ProjectReference {
+    /// Either the path to the referenced config or the directory that contains its `tsconfig.json`
+    pub path: String,
+}
+
+/// Configuration specific to `bndl`, declared in a `bndl` section of either
+/// the `tsconfig.json` or the `package.json`
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BndlConfig {
+    /// Globs of non code files that should be copied over to the output directory
+    pub assets: Option<Vec<String>>,
+    /// Compiler options that only apply to some of the files, applied in order
+    pub overrides: Option<Vec<CompilerOptionsOverride>>,
+    /// Target the Node version in `engines.node` of the `package.json` through SWC's `env`
+    /// instead of the `target` in the `tsconfig.json`
+    pub target_engines: Option<bool>,
+}
+
+/// Partial compiler options for the files matching `files` but not `exclude`, which
+/// take priority over the ones in the `tsconfig.json`, similar to `test` and `exclude` in `.swcrc`
+#[allow(non_snake_case)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CompilerOptionsOverride {
+    pub files: Vec<String>,
+    pub exclude: Option<Vec<String>>,
+    pub compilerOptions: CompilerOptions,
 }
 
 impl TsConfigJson {
@@ -101,6 +534,54 @@ impl TsConfigJson {
                     esModuleInterop: child_options
                         .esModuleInterop
                         .or(base_options.esModuleInterop),
+                    allowSyntheticDefaultImports: child_options
+                        .allowSyntheticDefaultImports
+                        .or(base_options.allowSyntheticDefaultImports),
+                    moduleResolution: child_options
+                        .moduleResolution
+                        .clone()
+                        .or_else(|| base_options.moduleResolution.clone()),
+                    jsx: child_options
+                        .jsx
+                        .clone()
+                        .or_else(|| base_options.jsx.clone()),
+                    jsxFactory: child_options
+                        .jsxFactory
+                        .clone()
+                        .or_else(|| base_options.jsxFactory.clone()),
+                    jsxFragmentFactory: child_options
+                        .jsxFragmentFactory
+                        .clone()
+                        .or_else(|| base_options.jsxFragmentFactory.clone()),
+                    jsxImportSource: child_options
+                        .jsxImportSource
+                        .clone()
+                        .or_else(|| base_options.jsxImportSource.clone()),
+                    emitDecoratorMetadata: child_options
+                        .emitDecoratorMetadata
+                        .or(base_options.emitDecoratorMetadata),
+                    useDefineForClassFields: child_options
+                        .useDefineForClassFields
+                        .or(base_options.useDefineForClassFields),
+                    importHelpers: child_options.importHelpers.or(base_options.importHelpers),
+                    verbatimModuleSyntax: child_options
+                        .verbatimModuleSyntax
+                        .or(base_options.verbatimModuleSyntax),
+                    preserveConstEnums: child_options
+                        .preserveConstEnums
+                        .or(base_options.preserveConstEnums),
+                    allowJs: child_options.allowJs.or(base_options.allowJs),
+                    rootDir: child_options
+                        .rootDir
+                        .clone()
+                        .or_else(|| base_options.rootDir.clone()),
+                    composite: child_options.composite.or(base_options.composite),
+                    other: base_options
+                        .other
+                        .clone()
+                        .into_iter()
+                        .chain(child_options.other.clone())
+                        .collect(),
                 })
             } else {
                 // Child is not a valid config, return the base and don't bother merging
@@ -170,6 +651,40 @@ impl TsConfigJson {
         content
     }
 
+    /// Maps every option declared in this specific config (ignoring `extends`) to its path
+    fn determine_sources(
+        &self,
+        config_path: &Path,
+    ) -> Result<HashMap<String, PathBuf>, serde_json::Error> {
+        let mut sources = HashMap::new();
+
+        if let serde_json::Value::Object(compiler_options) =
+            serde_json::to_value(&self.compilerOptions)?
+        {
+            for (option, value) in compiler_options {
+                if !value.is_null() {
+                    sources.insert(
+                        format!("compilerOptions.{option}"),
+                        config_path.to_path_buf(),
+                    );
+                }
+            }
+        }
+
+        let fields = [
+            ("include", self.include.is_some()),
+            ("exclude", self.exclude.is_some()),
+            ("bndl", self.bndl.is_some()),
+        ];
+        for (field, is_declared) in fields {
+            if is_declared {
+                sources.insert(field.to_string(), config_path.to_path_buf());
+            }
+        }
+
+        Ok(sources)
+    }
+
     fn load_and_merge_tsconfig(
         config_path: &Path,
         internal_packages: &HashMap<String, PathBuf>,
@@ -177,6 +692,16 @@ impl TsConfigJson {
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let config_str = Self::fetch_config_content(config_path, internal_packages, cache);
         let mut tsconfig: Self = serde_json::from_str(&config_str)?;
+        tsconfig.sources = tsconfig.determine_sources(config_path)?;
+        tsconfig.config_files = vec![config_path.to_path_buf()];
+
+        let declares_paths = tsconfig
+            .compilerOptions
+            .as_ref()
+            .is_some_and(|compiler_options| compiler_options.paths.is_some());
+        if declares_paths && config_path.exists() {
+            tsconfig.paths_base_dir = config_path.parent().map(Path::to_path_buf);
+        }
 
         if let Some(ref extends) = tsconfig.extends {
             let base_config_path = if extends.starts_with('.') {
@@ -190,17 +715,154 @@ impl TsConfigJson {
             let base_tsconfig =
                 Self::load_and_merge_tsconfig(&base_config_path, internal_packages, cache)?;
 
+            if !declares_paths {
+                tsconfig.paths_base_dir = base_tsconfig.paths_base_dir.clone();
+            }
+
             tsconfig.compilerOptions = Self::merge_compiler_options(
                 &base_tsconfig.compilerOptions,
                 &tsconfig.compilerOptions,
             );
+
+            // Like `tsc`, the include and exclude are inherited when the child doesn't specify them,
+            // but stay relative to the config that declared them
+            let base_dir = Path::new(extends)
+                .parent()
+                .filter(|_| extends.starts_with('.'))
+                .unwrap_or(Path::new(""));
+            let rebase = |patterns: Vec<String>| {
+                patterns
+                    .iter()
+                    .map(|pattern| Self::rebase_pattern(pattern, base_dir))
+                    .collect()
+            };
+            tsconfig.include = tsconfig.include.or(base_tsconfig.include.map(rebase));
+            tsconfig.exclude = tsconfig.exclude.or(base_tsconfig.exclude.map(rebase));
+            tsconfig.bndl = tsconfig.bndl.or(base_tsconfig.bndl);
+
+            // Unlike the other fields, `references` are never inherited from the base config
+
+            for (option, source) in base_tsconfig.sources {
+                tsconfig.sources.entry(option).or_insert(source);
+            }
+            tsconfig.config_files.extend(base_tsconfig.config_files);
         }
 
         Ok(tsconfig)
     }
 
+    /// Makes an include or exclude pattern of a base config relative to the config extending it.
+    /// Patterns using `${configDir}` are left alone since they are relative to the final config.
+    fn rebase_pattern(pattern: &str, base_dir: &Path) -> String {
+        if pattern.contains("${configDir}") || base_dir.as_os_str().is_empty() {
+            return pattern.to_string();
+        }
+
+        normalize_path(&base_dir.join(pattern))
+            .to_string_lossy()
+            .to_string()
+    }
+
+    /// Determines what `${configDir}` should expand to for the given config path.
+    /// We keep it relative to the project directory since that is how all other
+    /// paths in the config are treated, and an empty string means the config lives
+    /// in the project directory itself.
+    fn determine_config_dir(config_path: &Path, project_dir: &Path) -> PathBuf {
+        let mut config_dir = config_path.parent().unwrap_or(Path::new("")).to_path_buf();
+
+        if config_dir.starts_with(project_dir) {
+            config_dir = config_dir.strip_prefix(project_dir).unwrap().to_path_buf();
+        }
+
+        if config_dir.starts_with("./") || config_dir == Path::new(".") {
+            config_dir = config_dir.strip_prefix(".").unwrap().to_path_buf();
+        }
+
+        config_dir
+    }
+
+    /// Replaces the `${configDir}` template variable introduced in TypeScript 5.5
+    fn expand_config_dir(value: &str, config_dir: &Path) -> String {
+        if !value.contains("${configDir}") {
+            return value.to_string();
+        }
+
+        if config_dir.as_os_str().is_empty() {
+            return value
+                .replace("${configDir}/", "")
+                .replace("${configDir}", ".");
+        }
+
+        value.replace("${configDir}", &config_dir.to_string_lossy())
+    }
+
+    /// `${configDir}` always refers to the directory of the config that is being loaded,
+    /// not the one in the `extends` chain that declared it. So we can only expand it
+    /// once the full chain has been merged.
+    fn substitute_config_dir(&mut self, config_path: &Path, project_dir: &Path) {
+        let config_dir = Self::determine_config_dir(config_path, project_dir);
+        let expand = |value: &String| Self::expand_config_dir(value, &config_dir);
+
+        // Path targets are joined onto the `baseUrl`, so they need an absolute directory
+        let absolute_config_dir = project_dir.join(&config_dir);
+        let expand_absolute = |value: &String| {
+            if !value.contains("${configDir}") {
+                return value.clone();
+            }
+
+            let expanded = Self::expand_config_dir(value, &absolute_config_dir);
+            normalize_path(Path::new(&expanded))
+                .to_string_lossy()
+                .to_string()
+        };
+
+        if let Some(ref mut compiler_options) = self.compilerOptions {
+            compiler_options.outDir = compiler_options.outDir.as_ref().map(expand);
+            compiler_options.declarationDir = compiler_options.declarationDir.as_ref().map(expand);
+            compiler_options.baseUrl = compiler_options.baseUrl.as_ref().map(expand);
+            compiler_options.rootDir = compiler_options.rootDir.as_ref().map(expand);
+
+            if let Some(ref mut paths) = compiler_options.paths {
+                for targets in paths.values_mut() {
+                    *targets = targets.iter().map(expand_absolute).collect();
+                }
+            }
+        }
+
+        self.include = self
+            .include
+            .as_ref()
+            .map(|include| include.iter().map(expand).collect());
+        self.exclude = self
+            .exclude
+            .as_ref()
+            .map(|exclude| exclude.iter().map(expand).collect());
+    }
+
+    /// Resolves the config paths of the referenced projects relative to the given config
+    pub fn resolve_references(&self, config_path: &Path) -> Vec<PathBuf> {
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+
+        self.references
+            .iter()
+            .flatten()
+            .map(|reference| {
+                let path = config_dir.join(&reference.path);
+
+                if path.extension().is_some_and(|ext| ext == "json") {
+                    path
+                } else {
+                    path.join("tsconfig.json")
+                }
+            })
+            .collect()
+    }
+
+    /// Loads the config and its `extends` chain, where `project_dir` is the directory
+    /// the paths in the config are relative to
     pub fn from_path(
         config_path: &Path,
+        project_dir: &Path,
         manager: &Manager,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         if !config_path.exists() {
@@ -210,7 +872,14 @@ impl TsConfigJson {
         // We fetch the cache beforehand to avoid causing a deadlock trying to lock the mutex multiple times
         let mut content_cache = TSCONFIG_CONTENT.lock().unwrap();
 
-        Self::load_and_merge_tsconfig(config_path, &manager.packages, content_cache.borrow_mut())
+        let mut tsconfig = Self::load_and_merge_tsconfig(
+            config_path,
+            &manager.packages,
+            content_cache.borrow_mut(),
+        )?;
+        tsconfig.substitute_config_dir(config_path, project_dir);
+
+        Ok(tsconfig)
     }
 }
 
@@ -274,17 +943,229 @@ impl From<&Options> for SerializableOptions {
     }
 }
 
-#[derive(Debug)]
+/// `BoolConfig` settings that SWC enables unless they're disabled explicitly
+const ENABLED_BY_DEFAULT_SWC_SETTINGS: [&str; 5] = [
+    "emitSourceMapColumns",
+    "error.filename",
+    "inlineSourcesContent",
+    "jsc.experimental.disableAllLints",
+    "jsc.transform.useDefineForClassFields",
+];
+
+/// The `compilerOptions` equivalent of a hand written `.swcrc`
+#[derive(Debug, Default)]
+pub struct ReverseConversion {
+    pub compiler_options: CompilerOptions,
+    /// SWC settings in the `.swcrc` that have no `tsconfig.json` equivalent
+    pub untranslated: Vec<String>,
+}
+
+impl ReverseConversion {
+    /// `BoolConfig` doesn't expose whether it was specified, but serializes to `null` when it wasn't
+    fn bool_option<const DEFAULT: bool>(config: BoolConfig<DEFAULT>) -> Option<bool> {
+        serde_json::to_value(config).ok()?.as_bool()
+    }
+
+    fn convert_module(compiler_options: &mut CompilerOptions, module: &ModuleConfig) {
+        let (module, common_js) = match module {
+            ModuleConfig::CommonJs(config) => ("commonjs", Some(config)),
+            ModuleConfig::Amd(amd) => ("amd", Some(&amd.config)),
+            ModuleConfig::Umd(umd) => ("umd", Some(&umd.config)),
+            ModuleConfig::SystemJs(_) => ("system", None),
+            ModuleConfig::Es6(_) => ("esnext", None),
+            ModuleConfig::NodeNext(_) => ("nodenext", None),
+        };
+
+        compiler_options.module = Some(module.to_string());
+        compiler_options.esModuleInterop =
+            common_js.map(|config| config.import_interop() != ImportInterop::None);
+    }
+
+    fn convert_jsx(compiler_options: &mut CompilerOptions, react: &react::Options, is_jsx: bool) {
+        compiler_options.jsx = match (react.runtime, react.development.unwrap_or_default()) {
+            (Some(Runtime::Automatic), true) => Some(String::from("react-jsxdev")),
+            (Some(Runtime::Automatic), false) => Some(String::from("react-jsx")),
+            (Some(Runtime::Classic), _) => Some(String::from("react")),
+            // SWC defaults to the classic runtime when parsing JSX
+            (None, _) if is_jsx => Some(String::from("react")),
+            (None, _) => None,
+        };
+        compiler_options.jsxFactory = react.pragma.clone();
+        compiler_options.jsxFragmentFactory = react.pragma_frag.clone();
+        compiler_options.jsxImportSource = react.import_source.clone();
+    }
+
+    fn convert_options(options: SerializableOptions) -> CompilerOptions {
+        let mut compiler_options = CompilerOptions::default();
+        let jsc = options.config.jsc;
+
+        compiler_options.target = jsc
+            .target
+            .and_then(|target| serde_json::to_value(target).ok())
+            .and_then(|target| target.as_str().map(String::from));
+
+        if let Some(ref module) = options.config.module {
+            Self::convert_module(&mut compiler_options, module);
+        }
+
+        match options.source_maps.or(options.config.source_maps) {
+            Some(SourceMapsConfig::Bool(source_map)) => {
+                compiler_options.sourceMap = Some(source_map)
+            }
+            Some(SourceMapsConfig::Str(source_map)) if source_map == "inline" => {
+                compiler_options.inlineSourceMap = Some(true)
+            }
+            _ => (),
+        }
+        compiler_options.inlineSources = Self::bool_option(options.config.inline_sources_content);
+
+        let base_url = jsc.base_url.to_string_lossy();
+        compiler_options.baseUrl = (!base_url.is_empty()).then(|| base_url.to_string());
+        compiler_options.paths = (!jsc.paths.is_empty()).then_some(jsc.paths);
+
+        let is_jsx = match jsc.syntax {
+            Some(Syntax::Typescript(syntax)) => syntax.tsx,
+            Some(Syntax::Es(syntax)) => {
+                compiler_options.allowJs = Some(true);
+                syntax.jsx
+            }
+            None => false,
+        };
+
+        let transform = jsc.transform.into_inner().unwrap_or_default();
+        Self::convert_jsx(&mut compiler_options, &transform.react, is_jsx);
+        compiler_options.experimentalDecorators = Self::bool_option(transform.legacy_decorator)
+            .or_else(|| transform.decorator_version.map(|_| false));
+        compiler_options.emitDecoratorMetadata = Self::bool_option(transform.decorator_metadata);
+        compiler_options.useDefineForClassFields =
+            Self::bool_option(transform.use_define_for_class_fields);
+        compiler_options.verbatimModuleSyntax = Self::bool_option(transform.verbatim_module_syntax);
+        #[allow(deprecated)]
+        let treat_const_enum_as_enum = transform.treat_const_enum_as_enum;
+        compiler_options.preserveConstEnums = Self::bool_option(treat_const_enum_as_enum);
+
+        compiler_options.removeComments =
+            Self::bool_option(jsc.preserve_all_comments).map(|preserve| !preserve);
+        compiler_options.importHelpers = Self::bool_option(jsc.external_helpers);
+        compiler_options.declaration = Self::bool_option(jsc.experimental.emit_isolated_dts);
+        compiler_options.outDir = options
+            .output_path
+            .map(|output_path| output_path.to_string_lossy().to_string());
+
+        compiler_options
+    }
+
+    fn is_translated(setting: &str) -> bool {
+        SwcSetting::find(setting).is_some_and(|entry| entry.reversible)
+    }
+
+    /// Whether a declared setting is what SWC would use anyway. Settings that aren't serialized
+    /// when unset (eg. `BoolConfig`) are only default when they're empty or disabled.
+    fn is_default(
+        setting: &str,
+        value: &serde_json::Value,
+        default: Option<&serde_json::Value>,
+    ) -> bool {
+        match (default, value) {
+            // Unset lint rules serialize as `[null, null]`, or `[]` once the nulls are removed
+            (_, serde_json::Value::Array(values)) if values.iter().all(|v| v.is_null()) => true,
+            (Some(default), value) if !default.is_null() => value == default,
+            (_, serde_json::Value::Bool(enabled)) => {
+                *enabled == ENABLED_BY_DEFAULT_SWC_SETTINGS.contains(&setting)
+            }
+            (_, serde_json::Value::Null) => true,
+            (_, serde_json::Value::String(string)) => string.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Collects the path of every setting that is declared with a value other than the default
+    /// of SWC, eg. `jsc.transform.react.runtime`
+    fn collect_settings(
+        setting: String,
+        value: &serde_json::Value,
+        default: Option<&serde_json::Value>,
+        settings: &mut Vec<String>,
+    ) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    let setting = if setting.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{setting}.{key}")
+                    };
+                    let default = default.and_then(|default| default.get(key));
+
+                    Self::collect_settings(setting, value, default, settings);
+                }
+            }
+            value if Self::is_default(&setting, value, default) => (),
+            _ => settings.push(setting),
+        }
+    }
+
+    /// Reads an `.swcrc` and converts it to the equivalent `compilerOptions`
+    pub fn from_swcrc(swcrc_path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(swcrc_path)
+            .map_err(|err| format!("Unable to read {:?}: {err}", swcrc_path))?;
+        let swcrc: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|err| format!("Unable to parse {:?}: {err}", swcrc_path))?;
+
+        if !swcrc.is_object() {
+            return Err(format!(
+                "Expected {:?} to contain a single config object",
+                swcrc_path
+            )
+            .into());
+        }
+
+        let options: SerializableOptions = serde_json::from_value(swcrc.clone())
+            .map_err(|err| format!("Invalid SWC options in {:?}: {err}", swcrc_path))?;
+
+        let mut defaults = serde_json::to_value(SerializableOptions::from(
+            &serde_json::from_value::<Options>(serde_json::json!({}))?,
+        ))?;
+        if let Some(module) = swcrc.get("module").and_then(determine_default_module) {
+            defaults["module"] = module;
+        }
+
+        let mut settings = vec![];
+        Self::collect_settings(String::new(), &swcrc, Some(&defaults), &mut settings);
+
+        Ok(Self {
+            compiler_options: Self::convert_options(options),
+            untranslated: settings
+                .into_iter()
+                .filter(|setting| !Self::is_translated(setting))
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OverrideGlobSet {
+    files: GlobSet,
+    exclude: GlobSet,
+    compiler_options: CompilerOptions,
+}
+
 pub struct GlobSetConfig {
     pub include: GlobSet,
     pub exclude: GlobSet,
+    pub assets: GlobSet,
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct CreateConverterOptions {
     pub manager: Option<Manager>,
+    /// The directory paths in the config are relative to, defaults to the current directory
+    pub project_dir: Option<PathBuf>,
     pub minify_output: Option<bool>,
     pub enable_experimental_swc_declarations: Option<bool>,
+    /// An explicit `.swcrc` to merge over the converted options, instead of the `bndl.swcrc`
+    /// that is picked up automatically when it lives next to the config
+    pub swcrc_path: Option<PathBuf>,
 }
 
 /// Convert a `tsconfig.json` into a `swc::config::Options`
@@ -294,13 +1175,20 @@ pub struct CreateConverterOptions {
 pub struct Converter {
     minify_output: Option<bool>,
     enable_experimental_swc_declarations: Option<bool>,
+    package_json: PackageJson,
+    /// Glob sets of the compiler option overrides, only built once they are needed
+    override_glob_sets: OnceLock<Vec<OverrideGlobSet>>,
+    /// User provided SWC options that take priority over the converted ones
+    swcrc: Option<(PathBuf, serde_json::Value)>,
+    package_json_path: PathBuf,
+    project_dir: PathBuf,
     pub tsconfig: TsConfigJson,
 }
 
 impl Converter {
     fn construct_glob_set(&self, glob_candidates: Option<Vec<String>>) -> GlobSet {
         let mut builder = GlobSetBuilder::new();
-        let app_dir = env::current_dir().unwrap_or(PathBuf::from("."));
+        let app_dir = &self.project_dir;
 
         if let Some(inputs) = glob_candidates {
             for input in inputs {
@@ -339,98 +1227,535 @@ impl Converter {
         let tsconfig = self.tsconfig.clone();
         let include = self.construct_glob_set(tsconfig.include);
         let exclude = self.construct_glob_set(tsconfig.exclude);
+        let assets = self.construct_asset_glob_set();
+
+        GlobSetConfig {
+            include,
+            exclude,
+            assets,
+        }
+    }
+
+    /// The directory the paths in the config, and therefore our inputs and outputs, are relative to
+    pub fn project_dir(&self) -> &Path {
+        &self.project_dir
+    }
+
+    /// The `bndl` section in the `tsconfig.json` takes priority over the one in the `package.json`
+    pub fn determine_bndl_config(&self) -> BndlConfig {
+        if let Some(ref bndl) = self.tsconfig.bndl {
+            return bndl.clone();
+        }
+
+        // An invalid section is already reported when the converter is created
+        match self.package_json.bndl.clone().map(serde_json::from_value) {
+            Some(Ok(bndl)) => bndl,
+            Some(Err(err)) => {
+                debug!("{err} for bndl section in package.json");
+                BndlConfig::default()
+            }
+            None => BndlConfig::default(),
+        }
+    }
+
+    /// Whether the package the config belongs to lists the given package as a dependency
+    pub fn has_dependency(&self, name: &str) -> bool {
+        self.package_json
+            .dependencies
+            .as_ref()
+            .is_some_and(|dependencies| dependencies.contains_key(name))
+    }
+
+    /// Asset globs are matched against paths relative to the project directory
+    fn construct_asset_glob_set(&self) -> GlobSet {
+        // Invalid globs are already reported when the converter is created
+        Self::construct_relative_glob_set(&self.determine_bndl_config().assets.unwrap_or_default())
+            .unwrap_or_else(|err| {
+                debug!("Ignoring assets due to an invalid glob: {err}");
+                GlobSet::empty()
+            })
+    }
+
+    /// Builds a glob set out of globs that are relative to the project directory
+    fn construct_relative_glob_set(globs: &[String]) -> Result<GlobSet, globset::Error> {
+        let mut builder = GlobSetBuilder::new();
+
+        for glob in globs {
+            builder.add(Glob::new(glob.trim_start_matches("./"))?);
+        }
+
+        builder.build()
+    }
+
+    fn construct_override_glob_sets(&self) -> Result<Vec<OverrideGlobSet>, globset::Error> {
+        self.determine_bndl_config()
+            .overrides
+            .unwrap_or_default()
+            .into_iter()
+            .map(|r#override| {
+                Ok(OverrideGlobSet {
+                    files: Self::construct_relative_glob_set(&r#override.files)?,
+                    exclude: Self::construct_relative_glob_set(
+                        &r#override.exclude.unwrap_or_default(),
+                    )?,
+                    compiler_options: r#override.compilerOptions,
+                })
+            })
+            .collect()
+    }
+
+    fn determine_override_glob_sets(&self) -> &[OverrideGlobSet] {
+        self.override_glob_sets.get_or_init(|| {
+            // Invalid globs are already reported when the converter is created
+            self.construct_override_glob_sets().unwrap_or_else(|err| {
+                debug!("Ignoring overrides due to an invalid glob: {err}");
+                vec![]
+            })
+        })
+    }
+
+    /// Creates a converter with the compiler options of all overrides matching the given file
+    /// merged on top of the `tsconfig.json`, or `None` when no override applies
+    fn determine_override_converter(&self, input_path: &Path) -> Option<Self> {
+        let relative_path = normalize_path(input_path);
+
+        let mut compiler_options = self.tsconfig.compilerOptions.clone();
+        let mut matched = false;
+
+        for r#override in self.determine_override_glob_sets() {
+            if r#override.files.is_match(&relative_path)
+                && !r#override.exclude.is_match(&relative_path)
+            {
+                compiler_options = TsConfigJson::merge_compiler_options(
+                    &compiler_options,
+                    &Some(r#override.compiler_options.clone()),
+                );
+                matched = true;
+            }
+        }
+
+        if !matched {
+            return None;
+        }
+
+        debug!("Applying compiler option overrides to {:?}", input_path);
 
-        GlobSetConfig { include, exclude }
+        let mut converter = self.clone();
+        converter.tsconfig.compilerOptions = compiler_options;
+
+        Some(converter)
     }
 
     fn convert_target_to_es_version(
         &self,
         target: &Option<String>,
-    ) -> Option<swc_ecma_ast::EsVersion> {
-        Some(match target {
+    ) -> Result<Option<swc_ecma_ast::EsVersion>, String> {
+        Ok(Some(match target {
             Some(target) => match target.to_lowercase().as_str() {
                 "es3" => swc_ecma_ast::EsVersion::Es3,
                 "es5" => swc_ecma_ast::EsVersion::Es5,
-                "es2015" => swc_ecma_ast::EsVersion::Es2015,
+                "es6" | "es2015" => swc_ecma_ast::EsVersion::Es2015,
                 "es2016" => swc_ecma_ast::EsVersion::Es2016,
                 "es2017" => swc_ecma_ast::EsVersion::Es2017,
                 "es2018" => swc_ecma_ast::EsVersion::Es2018,
                 "es2019" => swc_ecma_ast::EsVersion::Es2019,
                 "es2020" => swc_ecma_ast::EsVersion::Es2020,
                 "es2021" => swc_ecma_ast::EsVersion::Es2021,
+                "es2022" => swc_ecma_ast::EsVersion::Es2022,
+                "es2023" => swc_ecma_ast::EsVersion::Es2023,
+                "es2024" => swc_ecma_ast::EsVersion::Es2024,
                 "esnext" => swc_ecma_ast::EsVersion::EsNext,
-                _ => swc_ecma_ast::EsVersion::latest(),
+                _ => return Err(format!("Unsupported target {:?} in tsconfig.json", target)),
             },
             None => swc_ecma_ast::EsVersion::latest(),
-        })
+        }))
     }
 
-    fn convert_module(
+    /// Extracts the lowest Node version out of an `engines.node` range like `>=18.12`,
+    /// `^20`, `18.x` or `20 || 18`, since that is the oldest runtime the output has to support
+    fn determine_node_version(range: &str) -> Option<String> {
+        let parse = |version: &str| -> Vec<u64> {
+            version
+                .split('.')
+                .map(|part| part.parse().unwrap_or_default())
+                .collect()
+        };
+
+        // Every alternative needs a lower bound, otherwise any version is allowed
+        range
+            .split("||")
+            .map(Self::determine_lower_bound)
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .min_by_key(|version| parse(version))
+    }
+
+    /// The lower bound of a single comparator set, which starts with the lowest version
+    fn determine_lower_bound(range: &str) -> Option<String> {
+        let range = range.trim();
+        if range.starts_with('<') {
+            return None;
+        }
+
+        let start = range.find(|c: char| c.is_ascii_digit())?;
+        let version: String = range[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        let version = version.trim_end_matches('.');
+
+        // SWC only understands `major`, `major.minor` and full semver versions
+        match version.split('.').count() {
+            1..=3 => Some(version.to_string()),
+            _ => None,
+        }
+    }
+
+    /// The SWC `env` based on the `engines.node` of the `package.json`, when opted in
+    fn determine_env(&self) -> Result<Option<serde_json::Value>, String> {
+        if !self
+            .determine_bndl_config()
+            .target_engines
+            .unwrap_or_default()
+        {
+            return Ok(None);
+        }
+
+        let range = self
+            .package_json
+            .engines
+            .as_ref()
+            .and_then(|engines| engines.get("node"))
+            .ok_or("targetEngines requires engines.node in the package.json")?;
+        let version = Self::determine_node_version(range)
+            .ok_or_else(|| format!("Unable to determine a Node version from {:?}", range))?;
+
+        debug!(
+            "Targeting Node {} based on engines.node {:?}",
+            version, range
+        );
+
+        Ok(Some(serde_json::json!({ "targets": { "node": version } })))
+    }
+
+    fn collect_compiler_option_warnings(
         &self,
-        module: Option<String>,
-        es_module_interop: Option<bool>,
-    ) -> Option<swc::config::ModuleConfig> {
-        Some(match module {
-            Some(module) => match module.to_lowercase().as_str() {
-                "amd" => swc::config::ModuleConfig::Amd(amd::Config {
-                    config: common_js::Config {
-                        no_interop: if let Some(interop) = es_module_interop {
-                            !interop
-                        } else {
-                            false
-                        },
-                        ..Default::default()
-                    },
-                    ..Default::default()
-                }),
-                "commonjs" => swc::config::ModuleConfig::CommonJs(common_js::Config {
-                    no_interop: if let Some(interop) = es_module_interop {
-                        !interop
-                    } else {
-                        false
-                    },
+        compiler_options: &CompilerOptions,
+        prefix: &str,
+    ) -> Vec<ConfigWarning> {
+        let warning = |option: &str, kind: ConfigWarningKind, message: &str| ConfigWarning {
+            option: format!("{prefix}.{option}"),
+            kind,
+            message: message.to_string(),
+        };
+
+        let mut unsupported: Vec<&String> = compiler_options
+            .other
+            .keys()
+            .filter(|option| !TYPE_CHECK_OPTIONS.contains(&option.as_str()))
+            .collect();
+        unsupported.sort();
+
+        let mut warnings: Vec<ConfigWarning> = unsupported
+            .into_iter()
+            .map(|option| warning(option, ConfigWarningKind::Unsupported, ""))
+            .collect();
+
+        if let Some(ref jsx) = compiler_options.jsx {
+            if !matches!(
+                jsx.to_lowercase().as_str(),
+                "react" | "react-jsx" | "react-jsxdev"
+            ) {
+                warnings.push(warning(
+                    "jsx",
+                    ConfigWarningKind::Lossy,
+                    &format!(
+                        "{:?} is compiled like \"react\" since SWC always transforms JSX",
+                        jsx
+                    ),
+                ));
+            }
+        }
+
+        if compiler_options.importHelpers.unwrap_or_default() {
+            warnings.push(warning(
+                "importHelpers",
+                ConfigWarningKind::Lossy,
+                "helpers are imported from @swc/helpers instead of tslib",
+            ));
+        }
+
+        if compiler_options.preserveConstEnums.unwrap_or_default() {
+            warnings.push(warning(
+                "preserveConstEnums",
+                ConfigWarningKind::Lossy,
+                "const enums that aren't exported are still inlined and removed by SWC",
+            ));
+        }
+
+        if compiler_options.target.is_some()
+            && self
+                .determine_bndl_config()
+                .target_engines
+                .unwrap_or_default()
+        {
+            warnings.push(warning(
+                "target",
+                ConfigWarningKind::Lossy,
+                "ignored in favour of engines.node since targetEngines is enabled",
+            ));
+        }
+
+        warnings
+    }
+
+    /// Fails with every option we can't translate (exactly), for `--strict-config`
+    pub fn check_strict_config(&self) -> Result<(), String> {
+        let warnings = self.collect_warnings();
+        if warnings.is_empty() {
+            return Ok(());
+        }
+
+        let warnings: Vec<String> = warnings.iter().map(ToString::to_string).collect();
+
+        Err(format!(
+            "Found {} unsupported tsconfig option(s): {}",
+            warnings.len(),
+            warnings.join("; ")
+        ))
+    }
+
+    /// Reports every option we don't translate, or translate in a way that makes the output
+    /// differ from `tsc`. Options that only affect type checking are left out.
+    pub fn collect_warnings(&self) -> Vec<ConfigWarning> {
+        let mut fields: Vec<&String> = self
+            .tsconfig
+            .other
+            .keys()
+            .filter(|field| !IGNORED_TSCONFIG_FIELDS.contains(&field.as_str()))
+            .collect();
+        fields.sort();
+
+        let mut warnings: Vec<ConfigWarning> = fields
+            .into_iter()
+            .map(|field| ConfigWarning {
+                option: field.clone(),
+                kind: ConfigWarningKind::Unsupported,
+                message: String::new(),
+            })
+            .collect();
+
+        warnings.extend(self.collect_compiler_option_warnings(
+            &self.tsconfig.compilerOptions.clone().unwrap_or_default(),
+            "compilerOptions",
+        ));
+
+        let overrides = self.determine_bndl_config().overrides.unwrap_or_default();
+        for (index, r#override) in overrides.iter().enumerate() {
+            warnings.extend(self.collect_compiler_option_warnings(
+                &r#override.compilerOptions,
+                &format!("bndl.overrides[{index}].compilerOptions"),
+            ));
+        }
+
+        warnings
+    }
+
+    /// Verifies the parts of the config that can't be converted, so we can error before
+    /// compiling anything
+    fn validate(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let compiler_options = self.tsconfig.compilerOptions.as_ref();
+        self.convert_target_to_es_version(&compiler_options.and_then(|c| c.target.clone()))?;
+
+        self.convert_module(&compiler_options.cloned().unwrap_or_default())?;
+
+        if let (None, Some(bndl)) = (&self.tsconfig.bndl, &self.package_json.bndl) {
+            serde_json::from_value::<BndlConfig>(bndl.clone()).map_err(|err| {
+                format!(
+                    "Invalid bndl section in {:?}: {err}",
+                    self.package_json_path
+                )
+            })?;
+        }
+
+        // Overrides are merged onto the compiler options of the project, like when they're applied
+        for r#override in self.determine_bndl_config().overrides.unwrap_or_default() {
+            self.convert_target_to_es_version(&r#override.compilerOptions.target)?;
+
+            let merged = TsConfigJson::merge_compiler_options(
+                &compiler_options.cloned(),
+                &Some(r#override.compilerOptions),
+            );
+            self.convert_module(&merged.unwrap_or_default())?;
+        }
+
+        Self::construct_relative_glob_set(&self.determine_bndl_config().assets.unwrap_or_default())
+            .map_err(|err| format!("Invalid asset glob in the bndl config: {err}"))?;
+        self.construct_override_glob_sets()
+            .map_err(|err| format!("Invalid override glob in the bndl config: {err}"))?;
+
+        self.determine_env()?;
+
+        // The `.swcrc` is valid on its own, but the merged result has to be valid as well
+        if let Some((ref swcrc_path, _)) = self.swcrc {
+            serde_json::from_value::<swc::config::Options>(self.convert_to_json()?).map_err(
+                |err| format!("Invalid SWC options after merging {:?}: {err}", swcrc_path),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Determines the module resolution, defaulting like `tsc` does based on `module`
+    fn determine_module_resolution(&self, compiler_options: &CompilerOptions) -> String {
+        if let Some(ref module_resolution) = compiler_options.moduleResolution {
+            return module_resolution.to_lowercase();
+        }
+
+        match compiler_options
+            .module
+            .as_deref()
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            Some("node16") => String::from("node16"),
+            Some("nodenext") => String::from("nodenext"),
+            Some("preserve") => String::from("bundler"),
+            _ => String::from("node10"),
+        }
+    }
+
+    /// The CommonJS interop follows `esModuleInterop`, or `allowSyntheticDefaultImports` when
+    /// only that is specified, and is enabled otherwise. When Node resolves the modules, ES
+    /// modules might import the output, so we annotate the exports for Node to detect them.
+    fn convert_common_js(&self, compiler_options: &CompilerOptions) -> common_js::Config {
+        let interop = compiler_options
+            .esModuleInterop
+            .or(compiler_options.allowSyntheticDefaultImports)
+            .unwrap_or(true);
+        let is_node_resolution = matches!(
+            self.determine_module_resolution(compiler_options).as_str(),
+            "node16" | "nodenext"
+        );
+
+        common_js::Config {
+            import_interop: Some(if interop {
+                ImportInterop::Swc
+            } else {
+                ImportInterop::None
+            }),
+            export_interop_annotation: is_node_resolution.then_some(true),
+            ..Default::default()
+        }
+    }
+
+    fn convert_module(
+        &self,
+        compiler_options: &CompilerOptions,
+    ) -> Result<Option<swc::config::ModuleConfig>, String> {
+        let common_js = self.convert_common_js(compiler_options);
+
+        Ok(Some(match compiler_options.module.as_deref() {
+            Some(module) => match module.to_lowercase().as_str() {
+                "amd" => swc::config::ModuleConfig::Amd(amd::Config {
+                    config: common_js,
                     ..Default::default()
                 }),
-                "cjs" => swc::config::ModuleConfig::CommonJs(Default::default()),
-                "es6" => swc::config::ModuleConfig::Es6(Default::default()),
-                "es2015" => swc::config::ModuleConfig::Es6(Default::default()),
-                "nodenext" => swc::config::ModuleConfig::NodeNext(Default::default()),
+                "commonjs" | "cjs" | "none" => swc::config::ModuleConfig::CommonJs(common_js),
+                // `preserve` keeps the module syntax as is, which for SWC means ES modules
+                "es6" | "es2015" | "es2020" | "es2022" | "esnext" | "preserve" => {
+                    swc::config::ModuleConfig::Es6(Default::default())
+                }
+                "node16" | "nodenext" => swc::config::ModuleConfig::NodeNext(Default::default()),
                 "umd" => swc::config::ModuleConfig::Umd(umd::Config {
-                    config: common_js::Config {
-                        no_interop: if let Some(interop) = es_module_interop {
-                            !interop
-                        } else {
-                            false
-                        },
-                        ..Default::default()
-                    },
+                    config: common_js,
                     ..Default::default()
                 }),
                 "system" => swc::config::ModuleConfig::SystemJs(Default::default()),
-                _ => swc::config::ModuleConfig::CommonJs(Default::default()),
+                _ => return Err(format!("Unsupported module {:?} in tsconfig.json", module)),
             },
-            None => swc::config::ModuleConfig::CommonJs(Default::default()),
-        })
+            None => swc::config::ModuleConfig::CommonJs(common_js),
+        }))
     }
 
-    fn determine_base_url(&self, base_url: Option<String>) -> PathBuf {
-        if base_url.is_none() {
-            return PathBuf::from("");
+    /// Maps the `jsx` related compiler options to the SWC react transform
+    fn convert_jsx(&self, compiler_options: &CompilerOptions) -> react::Options {
+        let (runtime, development) = match compiler_options.jsx.as_deref() {
+            Some(jsx) => match jsx.to_lowercase().as_str() {
+                "react-jsx" => (Some(Runtime::Automatic), Some(false)),
+                "react-jsxdev" => (Some(Runtime::Automatic), Some(true)),
+                "react" => (Some(Runtime::Classic), Some(false)),
+                _ => {
+                    // SWC always transforms JSX so `preserve` and `react-native` can't be honoured
+                    debug!("Unsupported jsx option {:?}, falling back to classic", jsx);
+                    (Some(Runtime::Classic), Some(false))
+                }
+            },
+            None => (None, None),
+        };
+
+        react::Options {
+            runtime,
+            development,
+            pragma: compiler_options.jsxFactory.clone(),
+            pragma_frag: compiler_options.jsxFragmentFactory.clone(),
+            import_source: compiler_options.jsxImportSource.clone(),
+            ..Default::default()
         }
+    }
 
-        let current_dir = env::current_dir().unwrap_or_default();
+    /// `tsc` defaults `useDefineForClassFields` to `true` only when targeting ES2022 or higher
+    fn determine_use_define_for_class_fields(
+        &self,
+        use_define_for_class_fields: Option<bool>,
+        target: Option<swc_ecma_ast::EsVersion>,
+    ) -> bool {
+        use_define_for_class_fields
+            .unwrap_or_else(|| target.unwrap_or_default() >= swc_ecma_ast::EsVersion::Es2022)
+    }
+
+    fn determine_base_url(&self, base_url: Option<String>, paths: &Option<Paths>) -> PathBuf {
+        let current_dir = &self.project_dir;
+
+        if let Some(base_url) = base_url {
+            return current_dir.join(base_url.trim_start_matches("./"));
+        }
 
-        current_dir.join(base_url.unwrap().trim_start_matches("./"))
+        // Since TypeScript 4.1 `paths` can be used without a `baseUrl`, in which case they
+        // resolve relative to the config that declares them
+        match self.tsconfig.paths_base_dir {
+            Some(ref paths_base_dir) if paths.is_some() => current_dir.join(paths_base_dir),
+            _ => PathBuf::from(""),
+        }
     }
 
     fn determine_paths(&self, base_url: &Path, paths: Option<Paths>) -> Paths {
-        if base_url.to_string_lossy().len() == 0 {
+        if base_url.to_string_lossy().is_empty() {
             return Default::default();
         }
 
         paths.unwrap_or_default()
     }
 
+    /// Finds the `paths` pattern a (non relative) import specifier matches, if any.
+    /// A bare `*` pattern is ignored since it matches every package import.
+    pub fn find_path_alias(&self, specifier: &str) -> Option<&str> {
+        let paths = self.tsconfig.compilerOptions.as_ref()?.paths.as_ref()?;
+
+        paths
+            .keys()
+            .map(String::as_str)
+            .find(|pattern| match pattern.split_once('*') {
+                Some(("", "")) => false,
+                Some((prefix, suffix)) => {
+                    specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix)
+                }
+                None => specifier == *pattern,
+            })
+    }
+
     /// Based on a given `tsconfig.json` determine the compiled output directory
     pub fn determine_out_dir(&self, override_out_dir: Option<PathBuf>) -> PathBuf {
         if let Some(out_dir) = override_out_dir {
@@ -442,15 +1767,691 @@ impl Converter {
         }
     }
 
-    pub fn convert(&self) -> swc::config::Options {
+    /// Based on a given `tsconfig.json` determine the root directory of the input files.
+    /// Like `tsc` we give priority to an explicit `rootDir` and otherwise infer it as
+    /// the longest common directory of all (non declaration) input files.
+    pub fn determine_root_dir(&self, input_files: &[PathBuf]) -> PathBuf {
+        if let Some(root_dir) = self
+            .tsconfig
+            .compilerOptions
+            .as_ref()
+            .and_then(|compiler_options| compiler_options.rootDir.as_ref())
+        {
+            return normalize_path(Path::new(root_dir));
+        }
+
+        let mut common_dir: Option<PathBuf> = None;
+        for file in input_files {
+            if is_declaration_file(file) {
+                continue;
+            }
+
+            let dir = normalize_path(file.parent().unwrap_or(Path::new("")));
+            common_dir = Some(match common_dir {
+                Some(common_dir) => common_dir
+                    .components()
+                    .zip(dir.components())
+                    .take_while(|(a, b)| a == b)
+                    .map(|(a, _)| a)
+                    .collect(),
+                None => dir,
+            });
+        }
+
+        common_dir.unwrap_or_default()
+    }
+
+    /// Mirror `tsc` in determining whether a file is emitted as an ES module. The `.mts` and `.cts`
+    /// extensions are explicit, otherwise `node16` and `nodenext` follow the package `type`.
+    pub fn is_esm_file(&self, input_path: &Path) -> bool {
+        match input_path.extension().and_then(|ext| ext.to_str()) {
+            Some("mts") | Some("mjs") => return true,
+            Some("cts") | Some("cjs") => return false,
+            _ => (),
+        }
+
+        let module = self
+            .tsconfig
+            .compilerOptions
+            .as_ref()
+            .and_then(|compiler_options| compiler_options.module.clone())
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match module.as_str() {
+            "node16" | "nodenext" => self.package_json.module_type.as_deref() == Some("module"),
+            "es6" | "es2015" | "es2020" | "es2022" | "esnext" | "preserve" => true,
+            _ => false,
+        }
+    }
+
+    /// Whether relative specifiers have to be fully resolved for the given file. Node requires
+    /// full paths for ES modules, but bundlers resolve extensionless specifiers themselves.
+    pub fn should_resolve_fully(&self, input_path: &Path) -> bool {
+        let compiler_options = self.tsconfig.compilerOptions.clone().unwrap_or_default();
+
+        self.is_esm_file(input_path)
+            && self.determine_module_resolution(&compiler_options) != "bundler"
+    }
+
+    /// Whether declaration files should be emitted at all, which `composite` projects imply
+    pub fn should_emit_declarations(&self) -> bool {
+        self.tsconfig
+            .compilerOptions
+            .as_ref()
+            .is_some_and(|compiler_options| {
+                compiler_options.declaration.unwrap_or_default()
+                    || compiler_options.composite.unwrap_or_default()
+            })
+    }
+
+    /// Whether declaration files should be emitted in-process by SWC using its isolated
+    /// declarations support, which is the default unless explicitly disabled in favor of `tsc`
+    pub fn should_emit_swc_declarations(&self) -> bool {
+        self.enable_experimental_swc_declarations.unwrap_or(true) && self.should_emit_declarations()
+    }
+
+    /// Determine the extension of the emitted declaration file for a given input file
+    pub fn determine_declaration_extension(&self, input_path: &Path) -> &'static str {
+        match self.determine_output_extension(input_path) {
+            "mjs" => "d.mts",
+            "cjs" => "d.cts",
+            _ => "d.ts",
+        }
+    }
+
+    /// Determine which file extensions should be compiled, like `tsc` we only pick up
+    /// JavaScript files when `allowJs` is enabled
+    pub fn determine_input_extensions(&self) -> Vec<&'static str> {
+        let mut extensions = vec!["ts", "tsx", "mts", "cts"];
+
+        let allow_js = self
+            .tsconfig
+            .compilerOptions
+            .as_ref()
+            .and_then(|compiler_options| compiler_options.allowJs)
+            .unwrap_or_default();
+
+        if allow_js {
+            extensions.extend(["js", "jsx", "mjs", "cjs"]);
+        }
+
+        extensions
+    }
+
+    /// Determine the parser syntax for a given input file based on its extension
+    fn determine_syntax(&self, input_path: &Path, syntax: Option<Syntax>) -> Option<Syntax> {
+        let decorators = match syntax {
+            Some(Syntax::Typescript(ts_syntax)) => ts_syntax.decorators,
+            Some(Syntax::Es(es_syntax)) => es_syntax.decorators,
+            None => false,
+        };
+
+        Some(match input_path.extension().and_then(|ext| ext.to_str()) {
+            Some("js") | Some("jsx") | Some("mjs") | Some("cjs") => Syntax::Es(EsSyntax {
+                // Like `tsc` we allow JSX in all JavaScript files
+                jsx: true,
+                decorators,
+                ..Default::default()
+            }),
+            Some("tsx") => Syntax::Typescript(TsSyntax {
+                tsx: true,
+                decorators,
+                ..Default::default()
+            }),
+            _ => Syntax::Typescript(TsSyntax {
+                tsx: false,
+                decorators,
+                ..Default::default()
+            }),
+        })
+    }
+
+    /// Determine the extension of the emitted file for a given input file
+    pub fn determine_output_extension(&self, input_path: &Path) -> &'static str {
+        match input_path.extension().and_then(|ext| ext.to_str()) {
+            Some("mts") | Some("mjs") => "mjs",
+            Some("cts") | Some("cjs") => "cjs",
+            _ => "js",
+        }
+    }
+
+    /// Adjust the converted options for a specific input file, since the parser syntax and
+    /// module format can differ per file depending on its extension and the package `type`,
+    /// and the compiler options can be overridden for some files in the `bndl` config
+    pub fn convert_for_file(
+        &self,
+        options: &swc::config::Options,
+        input_path: &Path,
+    ) -> Result<swc::config::Options, Box<dyn std::error::Error>> {
+        match self.determine_override_converter(input_path) {
+            Some(converter) => {
+                let converted = converter.convert()?;
+                let options = swc::config::Options {
+                    config: converted.config,
+                    source_maps: converted.source_maps,
+                    ..options.clone()
+                };
+
+                Ok(converter.adjust_for_file(&options, input_path))
+            }
+            None => Ok(self.adjust_for_file(options, input_path)),
+        }
+    }
+
+    /// Adjusts the syntax and module system to the given file
+    fn adjust_for_file(
+        &self,
+        options: &swc::config::Options,
+        input_path: &Path,
+    ) -> swc::config::Options {
+        let mut options = options.clone();
+        let is_esm = self.is_esm_file(input_path);
+
+        options.config.jsc.syntax = self.determine_syntax(input_path, options.config.jsc.syntax);
+
+        // A `module` from the `.swcrc` is taken as is, rather than following the file extension
+        if self
+            .swcrc
+            .as_ref()
+            .is_some_and(|(_, swcrc)| swcrc.get("module").is_some())
+        {
+            return options;
+        }
+
+        options.config.module = match options.config.module {
+            Some(ModuleConfig::CommonJs(_)) | Some(ModuleConfig::NodeNext(_)) | None if is_esm => {
+                Some(ModuleConfig::NodeNext(Default::default()))
+            }
+            Some(ModuleConfig::Es6(_)) | Some(ModuleConfig::NodeNext(_)) if !is_esm => {
+                let compiler_options = self.tsconfig.compilerOptions.clone().unwrap_or_default();
+
+                Some(ModuleConfig::CommonJs(
+                    self.convert_common_js(&compiler_options),
+                ))
+            }
+            module => module,
+        };
+
+        options
+    }
+
+    /// Deep merges `overlay` into `base`, where objects are merged key by key and everything
+    /// else is replaced. Objects of a different `type` (eg. `module`) are replaced as a whole
+    /// since their fields don't carry over.
+    fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+        match (base, overlay) {
+            (serde_json::Value::Object(base), serde_json::Value::Object(overlay))
+                if base.get("type").is_none()
+                    || overlay.get("type").is_none()
+                    || base.get("type") == overlay.get("type") =>
+            {
+                for (key, value) in overlay {
+                    match base.get_mut(&key) {
+                        Some(base_value) if !base_value.is_null() => {
+                            Self::merge_json(base_value, value)
+                        }
+                        _ => {
+                            base.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (base, overlay) => *base = overlay,
+        }
+    }
+
+    /// Not every option survives a round trip through `null`, so we drop those before merging
+    fn remove_null_values(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|_, value| !value.is_null());
+                map.values_mut().for_each(Self::remove_null_values);
+            }
+            serde_json::Value::Array(values) => {
+                values.iter_mut().for_each(Self::remove_null_values);
+            }
+            _ => (),
+        }
+    }
+
+    /// Merges the user provided `.swcrc` over the converted options. We merge on the JSON
+    /// representation since not every option (eg. `env`) can be serialized back by SWC.
+    fn merge_swcrc(
+        mut merged: serde_json::Value,
+        swcrc: &serde_json::Value,
+    ) -> Result<serde_json::Value, serde_json::Error> {
+        Self::remove_null_values(&mut merged);
+        Self::merge_json(&mut merged, swcrc.clone());
+
+        // SWC refuses `env` in combination with `jsc.target`, so the `env` of the user wins
+        // unless they explicitly declared both
+        if swcrc.get("env").is_some() && swcrc.pointer("/jsc/target").is_none() {
+            if let Some(jsc) = merged.get_mut("jsc").and_then(|jsc| jsc.as_object_mut()) {
+                jsc.remove("target");
+            }
+        }
+
+        Ok(merged)
+    }
+
+    /// Loads the `.swcrc` to merge over the converted options, if there is any
+    fn load_swcrc(
+        config_dir: &Path,
+        project_dir: &Path,
+        swcrc_path: Option<PathBuf>,
+    ) -> Result<Option<(PathBuf, serde_json::Value)>, Box<dyn std::error::Error>> {
+        let swcrc_path = match swcrc_path {
+            // An explicit `.swcrc` is relative to the project, like the paths in the config
+            Some(swcrc_path) => project_dir.join(swcrc_path),
+            None if config_dir.join("bndl.swcrc").exists() => config_dir.join("bndl.swcrc"),
+            None => return Ok(None),
+        };
+
+        debug!("Merging {:?} over the converted options", swcrc_path);
+
+        let content = fs::read_to_string(&swcrc_path)
+            .map_err(|err| format!("Unable to read {:?}: {err}", swcrc_path))?;
+        let swcrc: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|err| format!("Unable to parse {:?}: {err}", swcrc_path))?;
+
+        if !swcrc.is_object() {
+            return Err(format!(
+                "Expected {:?} to contain a single config object",
+                swcrc_path
+            )
+            .into());
+        }
+
+        // Validate early so we don't have to fail halfway through a compilation
+        serde_json::from_value::<swc::config::Options>(swcrc.clone())
+            .map_err(|err| format!("Invalid SWC options in {:?}: {err}", swcrc_path))?;
+
+        Ok(Some((swcrc_path, swcrc)))
+    }
+
+    pub fn convert(&self) -> Result<swc::config::Options, Box<dyn std::error::Error>> {
+        match self.swcrc {
+            Some(_) => Ok(serde_json::from_value(self.convert_to_json()?)?),
+            None => self.convert_tsconfig(),
+        }
+    }
+
+    /// The JSON representation of the converted options, including the merged `.swcrc`
+    pub fn convert_to_json(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let options = self.convert_tsconfig_to_json()?;
+
+        match self.swcrc {
+            Some((_, ref swcrc)) => Ok(Self::merge_swcrc(options, swcrc)?),
+            None => Ok(options),
+        }
+    }
+
+    /// The Node version targeted through `engines.node`, when opted in
+    fn determine_target_node_version(&self) -> Result<Option<String>, String> {
+        Ok(self.determine_env()?.and_then(|env| {
+            env.pointer("/targets/node")
+                .and_then(|version| version.as_str())
+                .map(String::from)
+        }))
+    }
+
+    /// Babel has no notion of ES versions, so we target the first Node version that
+    /// supports the entire ES version instead
+    fn convert_es_version_to_node_version(target: swc_ecma_ast::EsVersion) -> Option<&'static str> {
+        match target {
+            swc_ecma_ast::EsVersion::Es3 | swc_ecma_ast::EsVersion::Es5 => None,
+            swc_ecma_ast::EsVersion::Es2015 => Some("6"),
+            swc_ecma_ast::EsVersion::Es2016 => Some("7"),
+            swc_ecma_ast::EsVersion::Es2017 => Some("8"),
+            swc_ecma_ast::EsVersion::Es2018 => Some("10"),
+            swc_ecma_ast::EsVersion::Es2019 => Some("12"),
+            swc_ecma_ast::EsVersion::Es2020 => Some("14"),
+            swc_ecma_ast::EsVersion::Es2021 => Some("16"),
+            swc_ecma_ast::EsVersion::Es2022 => Some("16.11"),
+            swc_ecma_ast::EsVersion::Es2023 => Some("20"),
+            swc_ecma_ast::EsVersion::Es2024 => Some("22"),
+            swc_ecma_ast::EsVersion::EsNext => Some("current"),
+        }
+    }
+
+    fn determine_module_name(&self) -> String {
+        self.tsconfig
+            .compilerOptions
+            .as_ref()
+            .and_then(|compiler_options| compiler_options.module.clone())
+            .unwrap_or_else(|| String::from("commonjs"))
+    }
+
+    fn convert_source_maps(source_maps: Option<SourceMapsConfig>) -> serde_json::Value {
+        match source_maps {
+            Some(SourceMapsConfig::Bool(source_maps)) => serde_json::Value::Bool(source_maps),
+            Some(SourceMapsConfig::Str(source_maps)) => serde_json::Value::String(source_maps),
+            None => serde_json::Value::Null,
+        }
+    }
+
+    /// Renders the converted options as esbuild build options. Paths are left out since
+    /// esbuild resolves them from the `tsconfig.json` itself.
+    fn convert_to_esbuild(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let options = self.convert_compiler_options()?;
+        let jsc = options.config.jsc;
+        let transform = jsc.transform.into_inner().unwrap_or_default();
+
+        let target = match self.determine_target_node_version()? {
+            Some(version) => Some(format!("node{version}")),
+            None => jsc
+                .target
+                .and_then(|target| serde_json::to_value(target).ok())
+                .and_then(|target| target.as_str().map(String::from)),
+        };
+        let format = match options.config.module {
+            Some(ModuleConfig::CommonJs(_)) | None => "cjs",
+            Some(ModuleConfig::Es6(_)) => "esm",
+            // The format of regular `.ts` files follows the package `type`
+            Some(ModuleConfig::NodeNext(_)) if self.is_esm_file(Path::new("index.ts")) => "esm",
+            Some(ModuleConfig::NodeNext(_)) => "cjs",
+            Some(_) => {
+                return Err(format!(
+                    "Unsupported module {:?} for esbuild",
+                    self.determine_module_name()
+                )
+                .into())
+            }
+        };
+        let jsx = match transform.react.runtime {
+            Some(Runtime::Automatic) => Some("automatic"),
+            Some(Runtime::Classic) => Some("transform"),
+            None => None,
+        };
+
+        let mut esbuild = serde_json::json!({
+            "platform": "node",
+            "format": format,
+            "target": target,
+            "outdir": options.output_path,
+            "sourcemap": Self::convert_source_maps(options.source_maps),
+            "sourcesContent": options.config.inline_sources_content.into_bool(),
+            "minify": options.config.minify.into_bool(),
+            "keepNames": jsc.keep_class_names.into_bool(),
+            "jsx": jsx,
+            "jsxDev": transform.react.development,
+            "jsxFactory": transform.react.pragma,
+            "jsxFragment": transform.react.pragma_frag,
+            "jsxImportSource": transform.react.import_source,
+            "tsconfigRaw": {
+                "compilerOptions": {
+                    "experimentalDecorators": transform.legacy_decorator.into_bool(),
+                    "useDefineForClassFields": transform.use_define_for_class_fields.into_bool(),
+                    "verbatimModuleSyntax": transform.verbatim_module_syntax.into_bool(),
+                }
+            },
+        });
+        Self::remove_null_values(&mut esbuild);
+
+        Ok(esbuild)
+    }
+
+    /// Renders the converted options as a Babel config, using the presets and plugins
+    /// matching the target, module and decorators
+    fn convert_to_babel(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let options = self.convert_compiler_options()?;
+        let jsc = options.config.jsc;
+        let transform = jsc.transform.into_inner().unwrap_or_default();
+
+        let targets = match self.determine_target_node_version()? {
+            Some(version) => Some(version),
+            None => jsc
+                .target
+                .and_then(Self::convert_es_version_to_node_version)
+                .map(String::from),
+        };
+        let modules = match options.config.module {
+            Some(ModuleConfig::CommonJs(_)) | None => serde_json::json!("commonjs"),
+            Some(ModuleConfig::Amd(_)) => serde_json::json!("amd"),
+            Some(ModuleConfig::Umd(_)) => serde_json::json!("umd"),
+            Some(ModuleConfig::SystemJs(_)) => serde_json::json!("systemjs"),
+            Some(ModuleConfig::Es6(_)) => serde_json::json!(false),
+            // The format of regular `.ts` files follows the package `type`
+            Some(ModuleConfig::NodeNext(_)) if self.is_esm_file(Path::new("index.ts")) => {
+                serde_json::json!(false)
+            }
+            Some(ModuleConfig::NodeNext(_)) => serde_json::json!("commonjs"),
+        };
+
+        let mut presets = vec![
+            serde_json::json!(["@babel/preset-env", {
+                "targets": targets.map(|version| serde_json::json!({ "node": version })),
+                "modules": modules,
+            }]),
+            serde_json::json!(["@babel/preset-typescript", {
+                "onlyRemoveTypeImports": transform.verbatim_module_syntax.into_bool(),
+            }]),
+        ];
+        if let Some(runtime) = transform.react.runtime {
+            presets.push(serde_json::json!(["@babel/preset-react", {
+                "runtime": if runtime == Runtime::Automatic { "automatic" } else { "classic" },
+                "development": transform.react.development,
+                "pragma": transform.react.pragma,
+                "pragmaFrag": transform.react.pragma_frag,
+                "importSource": transform.react.import_source,
+            }]));
+        }
+
+        let mut plugins = vec![];
+        if transform.legacy_decorator.into_bool() {
+            // The metadata has to be emitted before the decorators are compiled away
+            if transform.decorator_metadata.into_bool() {
+                plugins.push(serde_json::json!(
+                    "babel-plugin-transform-typescript-metadata"
+                ));
+            }
+
+            plugins
+                .push(serde_json::json!(["@babel/plugin-proposal-decorators", { "legacy": true }]));
+        }
+        // TC39 decorators don't need to be enabled in the `tsconfig.json`, so we can't tell
+        // whether they're used and leave adding `@babel/plugin-proposal-decorators` to the user
+
+        let mut babel = serde_json::json!({
+            "presets": presets,
+            "plugins": (!plugins.is_empty()).then_some(plugins),
+            "assumptions": {
+                "setPublicClassFields": !transform.use_define_for_class_fields.into_bool(),
+            },
+            "sourceMaps": Self::convert_source_maps(options.source_maps),
+            "comments": jsc.preserve_all_comments.into_bool(),
+            "minified": options.config.minify.into_bool(),
+        });
+        Self::remove_null_values(&mut babel);
+
+        Ok(babel)
+    }
+
+    /// Renders the `tsconfig.json` for the given tool, `.swcrc` options excluded for other tools
+    pub fn convert_to_format(
+        &self,
+        format: OutputFormat,
+    ) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        match format {
+            OutputFormat::Swc => self.convert_to_json(),
+            OutputFormat::Esbuild => self.convert_to_esbuild(),
+            OutputFormat::Babel => self.convert_to_babel(),
+        }
+    }
+
+    /// The merged `tsconfig.json` after following the `extends` chain, like `tsc --showConfig`,
+    /// with the `paths` resolved to where they point and the configs that contributed
+    pub fn show_config(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let mut config = serde_json::to_value(&self.tsconfig)?;
+
         if let Some(compiler_options) = self.tsconfig.compilerOptions.clone() {
-            let base_url = self.determine_base_url(compiler_options.baseUrl);
+            let base_url =
+                self.determine_base_url(compiler_options.baseUrl, &compiler_options.paths);
+            let paths: Paths = self
+                .determine_paths(&base_url, compiler_options.paths)
+                .into_iter()
+                .map(|(pattern, targets)| {
+                    let targets = targets
+                        .iter()
+                        .map(|target| {
+                            let target = target.trim_start_matches("./");
+                            base_url.join(target).to_string_lossy().to_string()
+                        })
+                        .collect();
+
+                    (pattern, targets)
+                })
+                .collect();
+
+            config["compilerOptions"]["paths"] = serde_json::to_value(paths)?;
+        }
+
+        // The `extends` chain has been followed already
+        if let Some(config) = config.as_object_mut() {
+            config.remove("extends");
+        }
+        config["configFiles"] = serde_json::to_value(&self.tsconfig.config_files)?;
+
+        Ok(config)
+    }
+
+    /// Where a given option was configured, if it was configured at all
+    fn determine_option_source(&self, option: &str) -> Option<OptionSource> {
+        let config = match option {
+            "engines.node" => self
+                .package_json
+                .engines
+                .as_ref()
+                .is_some_and(|engines| engines.contains_key("node"))
+                .then(|| Some(self.package_json_path.clone())),
+            "--minify" => self.minify_output.unwrap_or_default().then_some(None),
+            _ => match option.strip_prefix("bndl.") {
+                // The `bndl` section can live in both the `tsconfig.json` and the `package.json`
+                Some(_) if self.tsconfig.bndl.is_some() => {
+                    self.tsconfig.sources.get("bndl").cloned().map(Some)
+                }
+                Some(_) => self
+                    .package_json
+                    .bndl
+                    .is_some()
+                    .then(|| Some(self.package_json_path.clone())),
+                None => self.tsconfig.sources.get(option).cloned().map(Some),
+            },
+        }?;
+
+        Some(OptionSource {
+            option: option.to_string(),
+            config,
+        })
+    }
+
+    fn explain_value(
+        &self,
+        setting: String,
+        value: serde_json::Value,
+        explanations: &mut Vec<OptionExplanation>,
+    ) {
+        let value = match value {
+            // Empty objects aren't emitted, so there is nothing to explain
+            serde_json::Value::Object(map) => {
+                for (key, value) in map {
+                    let setting = if setting.is_empty() {
+                        key
+                    } else {
+                        format!("{setting}.{key}")
+                    };
+
+                    self.explain_value(setting, value, explanations);
+                }
+
+                return;
+            }
+            serde_json::Value::String(ref string) if string.is_empty() => return,
+            value => value,
+        };
+
+        // Whatever the `.swcrc` declares always wins
+        if let Some((ref swcrc_path, ref swcrc)) = self.swcrc {
+            let pointer = format!("/{}", setting.replace('.', "/"));
+            if swcrc.pointer(&pointer).is_some() {
+                return explanations.push(OptionExplanation {
+                    sources: vec![OptionSource {
+                        option: setting.clone(),
+                        config: Some(swcrc_path.clone()),
+                    }],
+                    setting,
+                    value,
+                });
+            }
+        }
+
+        let options = SwcSetting::find(&setting)
+            .map(|entry| entry.origins)
+            .unwrap_or_default();
+
+        explanations.push(OptionExplanation {
+            sources: options
+                .iter()
+                .filter_map(|option| self.determine_option_source(option))
+                .collect(),
+            setting,
+            value,
+        });
+    }
+
+    /// Explains for every converted SWC setting which options in which config produced it
+    pub fn explain(&self) -> Result<Vec<OptionExplanation>, Box<dyn std::error::Error>> {
+        // Explain exactly what ends up in the emitted `.swcrc`
+        let mut options = self.convert_to_json()?;
+        remove_unwanted_values(&mut options);
+
+        let mut explanations = vec![];
+        self.explain_value(String::new(), options, &mut explanations);
+
+        Ok(explanations)
+    }
+
+    fn convert_tsconfig(&self) -> Result<swc::config::Options, Box<dyn std::error::Error>> {
+        let mut options = self.convert_compiler_options()?;
+
+        // SWC refuses `env` in combination with `jsc.target`
+        if let Some(env) = self.determine_env()? {
+            options.config.env = Some(serde_json::from_value(env)?);
+            options.config.jsc.target = None;
+        }
+
+        Ok(options)
+    }
+
+    /// Like `convert_tsconfig` but in the JSON representation, since SWC can't serialize `env`
+    fn convert_tsconfig_to_json(&self) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+        let mut options =
+            serde_json::to_value(SerializableOptions::from(&self.convert_tsconfig()?))?;
+
+        if let Some(env) = self.determine_env()? {
+            options["env"] = env;
+        }
+
+        Ok(options)
+    }
+
+    fn convert_compiler_options(&self) -> Result<swc::config::Options, String> {
+        if let Some(compiler_options) = self.tsconfig.compilerOptions.clone() {
+            let target = self.convert_target_to_es_version(&compiler_options.target)?;
+            let module = self.convert_module(&compiler_options)?;
+            let react = self.convert_jsx(&compiler_options);
+            // Mimic `tsc` by only using legacy decorator semantics (and emitting metadata)
+            // when explicitly opted in, otherwise fall back to TC39 decorators
+            let legacy_decorators = compiler_options.experimentalDecorators.unwrap_or_default();
+            let base_url =
+                self.determine_base_url(compiler_options.baseUrl, &compiler_options.paths);
             let paths = self.determine_paths(&base_url, compiler_options.paths);
             let inline_source_map = compiler_options.inlineSourceMap.unwrap_or(false);
             let inline_sources_content = compiler_options.inlineSources.unwrap_or(false);
-            let out_dir = compiler_options.outDir.unwrap_or_default();
+            let out_dir = compiler_options.outDir.clone().unwrap_or_default();
 
-            swc::config::Options {
+            Ok(swc::config::Options {
                 output_path: if out_dir.is_empty() {
                     None
                 } else {
@@ -465,8 +2466,7 @@ impl Converter {
                 },
                 config: swc::config::Config {
                     minify: BoolConfig::from(self.minify_output),
-                    module: self
-                        .convert_module(compiler_options.module, compiler_options.esModuleInterop),
+                    module,
                     inline_sources_content: BoolConfig::from(inline_sources_content),
                     source_maps: if inline_source_map {
                         Some(swc::config::SourceMapsConfig::Str(String::from("inline")))
@@ -479,24 +2479,52 @@ impl Converter {
                         base_url,
                         paths,
                         transform: Some(swc::config::TransformConfig {
-                            legacy_decorator: BoolConfig::new(Some(false)),
+                            react,
+                            legacy_decorator: BoolConfig::new(Some(legacy_decorators)),
                             decorator_metadata: BoolConfig::new(Some(
-                                compiler_options.experimentalDecorators.unwrap_or_default(),
+                                legacy_decorators
+                                    && compiler_options.emitDecoratorMetadata.unwrap_or_default(),
                             )),
+                            decorator_version: if legacy_decorators {
+                                None
+                            } else {
+                                Some(DecoratorVersion::V202203)
+                            },
+                            use_define_for_class_fields: BoolConfig::new(Some(
+                                self.determine_use_define_for_class_fields(
+                                    compiler_options.useDefineForClassFields,
+                                    target,
+                                ),
+                            )),
+                            verbatim_module_syntax: BoolConfig::new(
+                                compiler_options.verbatimModuleSyntax,
+                            ),
+                            #[allow(deprecated)]
+                            treat_const_enum_as_enum: BoolConfig::new(
+                                compiler_options.preserveConstEnums,
+                            ),
                             ..Default::default()
                         })
                         .into(),
-                        preserve_all_comments: if compiler_options.removeComments.is_some() {
-                            BoolConfig::new(Some(!compiler_options.removeComments.unwrap()))
+                        preserve_all_comments: if let Some(remove_comments) =
+                            compiler_options.removeComments
+                        {
+                            BoolConfig::new(Some(!remove_comments))
                         } else {
                             BoolConfig::new(Some(true))
                         },
                         keep_class_names: BoolConfig::new(Some(true)),
-                        target: self.convert_target_to_es_version(&compiler_options.target),
-                        syntax: Some(Syntax::Typescript(TsConfig {
-                            dts: self.enable_experimental_swc_declarations.unwrap_or(false)
-                                && compiler_options.declaration.unwrap_or_default(),
-                            decorators: compiler_options.experimentalDecorators.unwrap_or_default(),
+                        external_helpers: BoolConfig::new(compiler_options.importHelpers),
+                        target,
+                        experimental: JscExperimental {
+                            emit_isolated_dts: BoolConfig::new(Some(
+                                self.should_emit_swc_declarations(),
+                            )),
+                            ..Default::default()
+                        },
+                        syntax: Some(Syntax::Typescript(TsSyntax {
+                            // Since TypeScript 5.0 decorators are always allowed, either legacy or TC39
+                            decorators: true,
                             ..Default::default()
                         })),
                         ..Default::default()
@@ -505,22 +2533,19 @@ impl Converter {
                 },
                 swcrc: true, // Needs to be forced to true since it otherwise defaults to `false`
                 ..Default::default()
-            }
+            })
         } else {
-            swc::config::Options {
+            Ok(swc::config::Options {
                 config: swc::config::Config {
                     minify: BoolConfig::from(self.minify_output),
                     jsc: JscConfig {
-                        syntax: Some(Syntax::Typescript(TsConfig {
-                            dts: self.enable_experimental_swc_declarations.unwrap_or(false),
-                            ..Default::default()
-                        })),
+                        syntax: Some(Syntax::Typescript(Default::default())),
                         ..Default::default()
                     },
                     ..Default::default()
                 },
                 ..Default::default()
-            }
+            })
         }
     }
 
@@ -533,21 +2558,1181 @@ impl Converter {
             None => Manager::new()?,
         };
 
-        Ok(Self {
-            tsconfig: TsConfigJson::from_path(config_path, &manager)?,
+        let project_dir = match options.project_dir {
+            Some(project_dir) => project_dir,
+            None => env::current_dir()?,
+        };
+
+        // The package.json that lives next to the config determines the package `type`
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+        let package_json_path = config_dir.join("package.json");
+
+        let converter = Self {
+            tsconfig: TsConfigJson::from_path(config_path, &project_dir, &manager)?,
+            package_json: PackageJson::from_path(&package_json_path),
+            package_json_path,
+            override_glob_sets: OnceLock::new(),
+            swcrc: Self::load_swcrc(config_dir, &project_dir, options.swcrc_path)?,
+            project_dir,
             minify_output: options.minify_output,
             enable_experimental_swc_declarations: options.enable_experimental_swc_declarations,
-        })
+        };
+        converter.validate()?;
+
+        Ok(converter)
     }
 
     pub fn from_tsconfig(
         tsconfig: &TsConfigJson,
         options: CreateConverterOptions,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        Ok(Self {
+        let project_dir = match options.project_dir {
+            Some(project_dir) => project_dir,
+            None => env::current_dir()?,
+        };
+        let package_json_path = project_dir.join("package.json");
+
+        let converter = Self {
             tsconfig: tsconfig.clone(),
+            package_json: PackageJson::from_path(&package_json_path),
+            package_json_path,
+            override_glob_sets: OnceLock::new(),
+            swcrc: Self::load_swcrc(&project_dir, &project_dir, options.swcrc_path)?,
+            project_dir,
             minify_output: options.minify_output,
             enable_experimental_swc_declarations: options.enable_experimental_swc_declarations,
-        })
+        };
+        converter.validate()?;
+
+        Ok(converter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A project directory with the given files, which is removed again once the test is done
+    struct TestProject(PathBuf);
+
+    impl TestProject {
+        fn new(files: &[(&str, &str)]) -> Self {
+            static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+            // Unique per test (and test run) so tests can run in parallel
+            let project_dir = env::temp_dir().join(format!(
+                "bndl-convert-test-{}-{}",
+                process::id(),
+                COUNT.fetch_add(1, Ordering::Relaxed)
+            ));
+
+            for (path, content) in files {
+                let path = project_dir.join(path);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, content).unwrap();
+            }
+
+            Self(project_dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TestProject {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn create_converter(project_dir: &Path) -> Result<Converter, Box<dyn std::error::Error>> {
+        create_converter_with(project_dir, CreateConverterOptions::default())
+    }
+
+    /// Creates a converter for the `tsconfig.json` of a project in a workspace without packages
+    fn create_converter_with(
+        project_dir: &Path,
+        options: CreateConverterOptions,
+    ) -> Result<Converter, Box<dyn std::error::Error>> {
+        Converter::from_path(
+            &project_dir.join("tsconfig.json"),
+            CreateConverterOptions {
+                manager: Some(Manager {
+                    root: project_dir.to_path_buf(),
+                    packages: HashMap::new(),
+                }),
+                project_dir: Some(project_dir.to_path_buf()),
+                ..options
+            },
+        )
+    }
+
+    #[test]
+    fn substitute_config_dir_expands_relative_to_the_config() {
+        let project_dir = Path::new("/project");
+        let mut tsconfig: TsConfigJson = serde_json::from_value(serde_json::json!({
+            "include": ["${configDir}/src"],
+            "compilerOptions": {
+                "outDir": "${configDir}/dist",
+                "paths": { "@shared/*": ["${configDir}/../shared/*"] },
+            },
+        }))
+        .unwrap();
+
+        tsconfig.substitute_config_dir(&project_dir.join("app/tsconfig.json"), project_dir);
+
+        let compiler_options = tsconfig.compilerOptions.unwrap();
+        assert_eq!(compiler_options.outDir.as_deref(), Some("app/dist"));
+        assert_eq!(
+            compiler_options.paths.unwrap()["@shared/*"],
+            vec![String::from("/project/shared/*")]
+        );
+        assert_eq!(tsconfig.include, Some(vec![String::from("app/src")]));
+    }
+
+    #[test]
+    fn inherited_include_and_exclude_are_relative_to_the_base_config() {
+        let project = TestProject::new(&[
+            (
+                "config/base.json",
+                r#"{ "include": ["./src", "${configDir}/src"], "exclude": ["../**/*.test.ts"] }"#,
+            ),
+            (
+                "app/tsconfig.json",
+                r#"{ "extends": "../config/base.json" }"#,
+            ),
+            ("app/package.json", "{}"),
+        ]);
+        let converter = create_converter(&project.path().join("app")).unwrap();
+
+        assert_eq!(
+            converter.tsconfig.include,
+            Some(vec![String::from("../config/src"), String::from("src")])
+        );
+        assert_eq!(
+            converter.tsconfig.exclude,
+            Some(vec![String::from("../**/*.test.ts")])
+        );
+    }
+
+    #[test]
+    fn convert_maps_compiler_options_from_the_extends_chain() {
+        let project = TestProject::new(&[
+            (
+                "base.json",
+                r#"{ "compilerOptions": { "jsx": "react-jsx", "jsxImportSource": "preact", "importHelpers": true } }"#,
+            ),
+            (
+                "tsconfig.json",
+                r#"{
+                    "extends": "./base.json",
+                    "compilerOptions": {
+                        "target": "es2022",
+                        "useDefineForClassFields": false,
+                        "verbatimModuleSyntax": true,
+                        "preserveConstEnums": true,
+                        "removeComments": true
+                    }
+                }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        let config = converter.convert_to_json().unwrap();
+
+        let expected = [
+            ("/jsc/target", serde_json::json!("es2022")),
+            ("/jsc/externalHelpers", serde_json::json!(true)),
+            ("/jsc/preserveAllComments", serde_json::json!(false)),
+            (
+                "/jsc/transform/react/runtime",
+                serde_json::json!("automatic"),
+            ),
+            (
+                "/jsc/transform/react/importSource",
+                serde_json::json!("preact"),
+            ),
+            (
+                "/jsc/transform/useDefineForClassFields",
+                serde_json::json!(false),
+            ),
+            (
+                "/jsc/transform/verbatimModuleSyntax",
+                serde_json::json!(true),
+            ),
+            (
+                "/jsc/transform/treatConstEnumAsEnum",
+                serde_json::json!(true),
+            ),
+        ];
+        for (pointer, value) in expected {
+            assert_eq!(config.pointer(pointer), Some(&value), "{pointer}");
+        }
+
+        // SWC only keeps the const enums that are exported
+        assert!(converter.collect_warnings().iter().any(|warning| {
+            warning.option == "compilerOptions.preserveConstEnums"
+                && warning.kind == ConfigWarningKind::Lossy
+        }));
+    }
+
+    #[test]
+    fn convert_only_emits_decorator_metadata_for_legacy_decorators() {
+        let cases = [
+            (
+                r#"{ "experimentalDecorators": true, "emitDecoratorMetadata": true }"#,
+                true,
+                true,
+            ),
+            (r#"{ "experimentalDecorators": true }"#, true, false),
+            (r#"{ "emitDecoratorMetadata": true }"#, false, false),
+            ("{}", false, false),
+        ];
+
+        for (compiler_options, legacy_decorator, decorator_metadata) in cases {
+            let project = TestProject::new(&[
+                (
+                    "tsconfig.json",
+                    &format!(r#"{{ "compilerOptions": {compiler_options} }}"#),
+                ),
+                ("package.json", "{}"),
+            ]);
+            let config = create_converter(project.path())
+                .unwrap()
+                .convert_to_json()
+                .unwrap();
+            let transform = &config["jsc"]["transform"];
+
+            assert_eq!(
+                transform["legacyDecorator"],
+                serde_json::json!(legacy_decorator),
+                "{compiler_options}"
+            );
+            assert_eq!(
+                transform["decoratorMetadata"],
+                serde_json::json!(decorator_metadata),
+                "{compiler_options}"
+            );
+            // TC39 decorators are used unless the legacy ones are enabled
+            assert_eq!(
+                transform["decoratorVersion"],
+                if legacy_decorator {
+                    serde_json::Value::Null
+                } else {
+                    serde_json::json!("2022-03")
+                },
+                "{compiler_options}"
+            );
+        }
+    }
+
+    #[test]
+    fn determine_root_dir_infers_the_common_directory_of_the_inputs() {
+        let project = TestProject::new(&[("tsconfig.json", "{}"), ("package.json", "{}")]);
+        let converter = create_converter(project.path()).unwrap();
+        let input_files = [
+            PathBuf::from("./src/index.ts"),
+            PathBuf::from("src/nested/util.ts"),
+            // Declaration files aren't emitted, so they don't influence the root directory
+            PathBuf::from("types/global.d.ts"),
+        ];
+
+        assert_eq!(
+            converter.determine_root_dir(&input_files),
+            PathBuf::from("src")
+        );
+        assert_eq!(
+            converter.determine_root_dir(&input_files[1..]),
+            PathBuf::from("src/nested")
+        );
+        assert_eq!(converter.determine_root_dir(&[]), PathBuf::new());
+    }
+
+    #[test]
+    fn determine_root_dir_prefers_the_explicit_root_dir() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "rootDir": "./src" } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+
+        assert_eq!(
+            converter.determine_root_dir(&[PathBuf::from("src/nested/util.ts")]),
+            PathBuf::from("src")
+        );
+    }
+
+    #[test]
+    fn is_esm_file_follows_the_extension_module_and_package_type() {
+        let cases = [
+            ("nodenext", r#"{ "name": "app", "type": "module" }"#, true),
+            (
+                "nodenext",
+                r#"{ "name": "app", "type": "commonjs" }"#,
+                false,
+            ),
+            ("node16", "{}", false),
+            ("es2022", "{}", true),
+            ("commonjs", r#"{ "name": "app", "type": "module" }"#, false),
+        ];
+
+        for (module, package_json, is_esm) in cases {
+            let project = TestProject::new(&[
+                (
+                    "tsconfig.json",
+                    &format!(r#"{{ "compilerOptions": {{ "module": "{module}" }} }}"#),
+                ),
+                ("package.json", package_json),
+            ]);
+            let converter = create_converter(project.path()).unwrap();
+
+            assert_eq!(
+                converter.is_esm_file(Path::new("src/index.ts")),
+                is_esm,
+                "{module} {package_json}"
+            );
+            // The explicit extensions win over the module and package type
+            assert!(converter.is_esm_file(Path::new("src/index.mts")));
+            assert!(!converter.is_esm_file(Path::new("src/index.cts")));
+        }
+    }
+
+    #[test]
+    fn determine_output_extension_mirrors_the_input_extension() {
+        let project = TestProject::new(&[
+            ("tsconfig.json", "{}"),
+            ("package.json", r#"{ "name": "app", "type": "module" }"#),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+
+        for (input, extension) in [
+            ("index.ts", "js"),
+            ("index.tsx", "js"),
+            ("index.mts", "mjs"),
+            ("index.cts", "cjs"),
+            ("index.mjs", "mjs"),
+        ] {
+            assert_eq!(
+                converter.determine_output_extension(Path::new(input)),
+                extension
+            );
+        }
+        assert_eq!(
+            converter.determine_declaration_extension(Path::new("index.mts")),
+            "d.mts"
+        );
+    }
+
+    #[test]
+    fn determine_input_extensions_only_includes_javascript_with_allow_js() {
+        let project = TestProject::new(&[("tsconfig.json", "{}"), ("package.json", "{}")]);
+        let converter = create_converter(project.path()).unwrap();
+        assert_eq!(
+            converter.determine_input_extensions(),
+            vec!["ts", "tsx", "mts", "cts"]
+        );
+
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "allowJs": true } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        assert_eq!(
+            converter.determine_input_extensions(),
+            vec!["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"]
+        );
+    }
+
+    #[test]
+    fn convert_for_file_picks_the_syntax_of_the_extension() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "allowJs": true, "jsx": "react-jsx" } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        let options = converter.convert().unwrap();
+        let syntax = |input: &str| {
+            converter
+                .convert_for_file(&options, Path::new(input))
+                .unwrap()
+                .config
+                .jsc
+                .syntax
+        };
+
+        assert!(matches!(
+            syntax("src/index.ts"),
+            Some(Syntax::Typescript(TsSyntax {
+                tsx: false,
+                decorators: true,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            syntax("src/App.tsx"),
+            Some(Syntax::Typescript(TsSyntax {
+                tsx: true,
+                decorators: true,
+                ..
+            }))
+        ));
+        for input in ["src/App.jsx", "src/index.mjs", "src/index.cjs"] {
+            assert!(
+                matches!(
+                    syntax(input),
+                    Some(Syntax::Es(EsSyntax {
+                        jsx: true,
+                        decorators: true,
+                        ..
+                    }))
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_rejects_an_invalid_bndl_config() {
+        let cases = [
+            (
+                r#"{ "bndl": { "assets": ["src/[a"] } }"#,
+                r#"{ "name": "app" }"#,
+            ),
+            ("{}", r#"{ "name": "app", "bndl": { "assets": "src/**" } }"#),
+        ];
+
+        for (tsconfig, package_json) in cases {
+            let project =
+                TestProject::new(&[("tsconfig.json", tsconfig), ("package.json", package_json)]);
+
+            assert!(
+                create_converter(project.path()).is_err(),
+                "{tsconfig} {package_json}"
+            );
+        }
+
+        // The `bndl` section of the tsconfig takes priority over the package.json
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "bndl": { "assets": ["src/**/*.graphql"] } }"#,
+            ),
+            (
+                "package.json",
+                r#"{ "name": "app", "bndl": { "assets": "src/**" } }"#,
+            ),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        assert_eq!(
+            converter.determine_bndl_config().assets,
+            Some(vec![String::from("src/**/*.graphql")])
+        );
+    }
+
+    #[test]
+    fn should_emit_swc_declarations_unless_tsc_is_requested() {
+        let cases = [
+            (r#"{ "declaration": true }"#, None, true),
+            (r#"{ "composite": true }"#, None, true),
+            (r#"{ "declaration": true }"#, Some(false), false),
+            ("{}", Some(true), false),
+        ];
+
+        for (compiler_options, enable_swc_declarations, should_emit) in cases {
+            let project = TestProject::new(&[
+                (
+                    "tsconfig.json",
+                    &format!(r#"{{ "compilerOptions": {compiler_options} }}"#),
+                ),
+                ("package.json", "{}"),
+            ]);
+            let converter = create_converter_with(
+                project.path(),
+                CreateConverterOptions {
+                    enable_experimental_swc_declarations: enable_swc_declarations,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            assert_eq!(
+                converter.should_emit_swc_declarations(),
+                should_emit,
+                "{compiler_options} {enable_swc_declarations:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn normalize_path_resolves_dot_components_lexically() {
+        let cases = [
+            ("./src/index.ts", "src/index.ts"),
+            ("src/../lib/./index.ts", "lib/index.ts"),
+            ("../../shared/src", "../../shared/src"),
+            ("src/../../shared", "../shared"),
+            ("/project/../..", "/"),
+        ];
+
+        for (path, normalized) in cases {
+            assert_eq!(normalize_path(Path::new(path)), PathBuf::from(normalized));
+        }
+    }
+
+    #[test]
+    fn paths_without_base_url_resolve_relative_to_the_declaring_config() {
+        let project = TestProject::new(&[
+            (
+                "config/base.json",
+                r#"{ "compilerOptions": { "paths": { "@shared/*": ["./shared/*"] } } }"#,
+            ),
+            (
+                "app/tsconfig.json",
+                r#"{ "extends": "../config/base.json" }"#,
+            ),
+            ("app/package.json", "{}"),
+        ]);
+        let options = create_converter(&project.path().join("app"))
+            .unwrap()
+            .convert()
+            .unwrap();
+
+        assert_eq!(
+            normalize_path(&options.config.jsc.base_url),
+            project.path().join("config")
+        );
+        assert_eq!(
+            options.config.jsc.paths["@shared/*"],
+            vec![String::from("./shared/*")]
+        );
+    }
+
+    #[test]
+    fn find_path_alias_matches_the_paths_patterns() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r##"{ "compilerOptions": { "paths": { "@/*": ["./src/*"], "#config": ["./src/config.ts"], "*": ["./types/*"] } } }"##,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+
+        assert_eq!(converter.find_path_alias("@/utils/x"), Some("@/*"));
+        assert_eq!(converter.find_path_alias("#config"), Some("#config"));
+        // A bare `*` would match every package import
+        assert_eq!(converter.find_path_alias("lodash"), None);
+    }
+
+    #[test]
+    fn convert_for_file_applies_the_matching_overrides() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{
+                    "compilerOptions": { "target": "es2022", "experimentalDecorators": true },
+                    "bndl": {
+                        "overrides": [
+                            {
+                                "files": ["src/legacy/**"],
+                                "exclude": ["src/legacy/modern.ts"],
+                                "compilerOptions": { "target": "es2017" }
+                            }
+                        ]
+                    }
+                }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        let options = converter.convert().unwrap();
+        let target = |input: &str| {
+            converter
+                .convert_for_file(&options, Path::new(input))
+                .unwrap()
+                .config
+                .jsc
+                .target
+        };
+
+        assert_eq!(
+            target("./src/legacy/old.ts"),
+            Some(swc_ecma_ast::EsVersion::Es2017)
+        );
+        assert_eq!(
+            target("src/legacy/modern.ts"),
+            Some(swc_ecma_ast::EsVersion::Es2022)
+        );
+        assert_eq!(
+            target("src/index.ts"),
+            Some(swc_ecma_ast::EsVersion::Es2022)
+        );
+
+        // The rest of the compiler options still apply to the overridden files
+        let legacy = converter
+            .convert_for_file(&options, Path::new("src/legacy/old.ts"))
+            .unwrap();
+        assert!(legacy
+            .config
+            .jsc
+            .transform
+            .into_inner()
+            .unwrap()
+            .legacy_decorator
+            .into_bool());
+    }
+
+    #[test]
+    fn validate_rejects_invalid_overrides() {
+        let cases = [
+            r#"{ "files": ["src/**"], "compilerOptions": { "module": "bogus" } }"#,
+            r#"{ "files": ["src/**"], "compilerOptions": { "target": "es1" } }"#,
+            r#"{ "files": ["src/[a"], "compilerOptions": {} }"#,
+        ];
+
+        for r#override in cases {
+            let project = TestProject::new(&[
+                (
+                    "tsconfig.json",
+                    &format!(r#"{{ "bndl": {{ "overrides": [{override}] }} }}"#),
+                ),
+                ("package.json", "{}"),
+            ]);
+
+            assert!(create_converter(project.path()).is_err(), "{override}");
+        }
+    }
+
+    #[test]
+    fn merge_json_merges_objects_and_replaces_everything_else() {
+        let mut base = serde_json::json!({
+            "jsc": { "target": "es2022", "transform": { "legacyDecorator": true } },
+            "module": { "type": "commonjs", "strictMode": true },
+            "sourceMaps": true,
+        });
+        Converter::merge_json(
+            &mut base,
+            serde_json::json!({
+                "jsc": { "transform": { "decoratorMetadata": true } },
+                "module": { "type": "es6" },
+                "sourceMaps": "inline",
+            }),
+        );
+
+        assert_eq!(
+            base,
+            serde_json::json!({
+                "jsc": {
+                    "target": "es2022",
+                    "transform": { "legacyDecorator": true, "decoratorMetadata": true },
+                },
+                // A different module type doesn't share its settings
+                "module": { "type": "es6" },
+                "sourceMaps": "inline",
+            })
+        );
+    }
+
+    #[test]
+    fn merge_swcrc_lets_env_replace_the_target() {
+        let converted =
+            serde_json::json!({ "jsc": { "target": "es2022", "keepClassNames": true } });
+
+        let merged = Converter::merge_swcrc(
+            converted.clone(),
+            &serde_json::json!({ "env": { "targets": { "node": "20" } } }),
+        )
+        .unwrap();
+        assert_eq!(merged.pointer("/jsc/target"), None);
+        assert_eq!(
+            merged.pointer("/jsc/keepClassNames"),
+            Some(&serde_json::json!(true))
+        );
+        assert_eq!(
+            merged.pointer("/env/targets/node"),
+            Some(&serde_json::json!("20"))
+        );
+
+        let merged = Converter::merge_swcrc(
+            converted,
+            &serde_json::json!({ "env": { "mode": "usage" }, "jsc": { "target": "es2020" } }),
+        )
+        .unwrap();
+        assert_eq!(
+            merged.pointer("/jsc/target"),
+            Some(&serde_json::json!("es2020"))
+        );
+    }
+
+    #[test]
+    fn convert_for_file_keeps_the_module_of_the_swcrc() {
+        let files = [
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "module": "nodenext" } }"#,
+            ),
+            ("package.json", r#"{ "name": "app", "type": "module" }"#),
+        ];
+        let module = |converter: &Converter, input: &str| {
+            let options = converter.convert().unwrap();
+            converter
+                .convert_for_file(&options, Path::new(input))
+                .unwrap()
+                .config
+                .module
+        };
+
+        let project = TestProject::new(&files);
+        let converter = create_converter(project.path()).unwrap();
+        assert!(matches!(
+            module(&converter, "src/index.ts"),
+            Some(ModuleConfig::NodeNext(_))
+        ));
+        assert!(matches!(
+            module(&converter, "src/index.cts"),
+            Some(ModuleConfig::CommonJs(_))
+        ));
+
+        let project = TestProject::new(&[
+            files[0],
+            files[1],
+            ("bndl.swcrc", r#"{ "module": { "type": "commonjs" } }"#),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        for input in ["src/index.ts", "src/index.mts"] {
+            assert!(
+                matches!(module(&converter, input), Some(ModuleConfig::CommonJs(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn determine_node_version_uses_the_lowest_version_of_the_range() {
+        let cases = [
+            (">=18.12", Some("18.12")),
+            ("^20", Some("20")),
+            ("18.x", Some("18")),
+            ("20 || 18", Some("18")),
+            (">=18.12 <21 || ^16.3", Some("16.3")),
+            ("<20", None),
+            ("*", None),
+        ];
+
+        for (range, version) in cases {
+            assert_eq!(
+                Converter::determine_node_version(range).as_deref(),
+                version,
+                "{range}"
+            );
+        }
+    }
+
+    #[test]
+    fn convert_targets_the_node_version_of_the_engines() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "target": "es2022" }, "bndl": { "targetEngines": true } }"#,
+            ),
+            (
+                "package.json",
+                r#"{ "name": "app", "engines": { "node": ">=18.12 <23" } }"#,
+            ),
+        ]);
+        let config = create_converter(project.path())
+            .unwrap()
+            .convert_to_json()
+            .unwrap();
+
+        assert_eq!(
+            config.pointer("/env/targets/node"),
+            Some(&serde_json::json!("18.12"))
+        );
+        // SWC refuses `env` in combination with a target
+        assert!(config["jsc"]["target"].is_null());
+    }
+
+    #[test]
+    fn validate_rejects_unsupported_targets() {
+        let cases = [
+            (
+                r#"{ "compilerOptions": { "target": "es1" } }"#,
+                r#"{ "name": "app" }"#,
+            ),
+            // `targetEngines` needs a Node version to target
+            (
+                r#"{ "bndl": { "targetEngines": true } }"#,
+                r#"{ "name": "app" }"#,
+            ),
+            (
+                r#"{ "bndl": { "targetEngines": true } }"#,
+                r#"{ "name": "app", "engines": { "node": "<20" } }"#,
+            ),
+        ];
+
+        for (tsconfig, package_json) in cases {
+            let project =
+                TestProject::new(&[("tsconfig.json", tsconfig), ("package.json", package_json)]);
+
+            assert!(
+                create_converter(project.path()).is_err(),
+                "{tsconfig} {package_json}"
+            );
+        }
+
+        for target in ["es5", "ES2022", "es2024", "esnext"] {
+            let project = TestProject::new(&[
+                (
+                    "tsconfig.json",
+                    &format!(r#"{{ "compilerOptions": {{ "target": "{target}" }} }}"#),
+                ),
+                ("package.json", "{}"),
+            ]);
+
+            assert!(create_converter(project.path()).is_ok(), "{target}");
+        }
+    }
+
+    #[test]
+    fn convert_module_maps_every_module_value() {
+        let project = TestProject::new(&[("tsconfig.json", "{}"), ("package.json", "{}")]);
+        let converter = create_converter(project.path()).unwrap();
+        let convert = |compiler_options: serde_json::Value| {
+            converter
+                .convert_module(&serde_json::from_value(compiler_options).unwrap())
+                .unwrap()
+                .unwrap()
+        };
+
+        for module in ["es6", "ES2015", "es2020", "es2022", "esnext", "preserve"] {
+            assert!(
+                matches!(
+                    convert(serde_json::json!({ "module": module })),
+                    ModuleConfig::Es6(_)
+                ),
+                "{module}"
+            );
+        }
+        for module in ["node16", "NodeNext"] {
+            assert!(
+                matches!(
+                    convert(serde_json::json!({ "module": module })),
+                    ModuleConfig::NodeNext(_)
+                ),
+                "{module}"
+            );
+        }
+        assert!(matches!(
+            convert(serde_json::json!({ "module": "amd" })),
+            ModuleConfig::Amd(_)
+        ));
+        assert!(matches!(
+            convert(serde_json::json!({ "module": "umd" })),
+            ModuleConfig::Umd(_)
+        ));
+        assert!(matches!(
+            convert(serde_json::json!({ "module": "system" })),
+            ModuleConfig::SystemJs(_)
+        ));
+        assert!(converter
+            .convert_module(
+                &serde_json::from_value(serde_json::json!({ "module": "bogus" })).unwrap()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn convert_module_derives_the_commonjs_interop() {
+        let project = TestProject::new(&[("tsconfig.json", "{}"), ("package.json", "{}")]);
+        let converter = create_converter(project.path()).unwrap();
+        let convert = |compiler_options: serde_json::Value| match converter
+            .convert_module(&serde_json::from_value(compiler_options).unwrap())
+            .unwrap()
+        {
+            Some(ModuleConfig::CommonJs(config)) => config,
+            module => panic!("Expected CommonJS, got {:?}", module),
+        };
+
+        let config = convert(serde_json::json!({}));
+        assert_eq!(config.import_interop, Some(ImportInterop::Swc));
+        assert_eq!(config.export_interop_annotation, None);
+
+        let config = convert(serde_json::json!({ "module": "commonjs", "esModuleInterop": false }));
+        assert_eq!(config.import_interop, Some(ImportInterop::None));
+
+        // `esModuleInterop` takes priority over `allowSyntheticDefaultImports`
+        let config = convert(serde_json::json!({
+            "module": "commonjs",
+            "esModuleInterop": true,
+            "allowSyntheticDefaultImports": false,
+        }));
+        assert_eq!(config.import_interop, Some(ImportInterop::Swc));
+        let config = convert(serde_json::json!({
+            "module": "commonjs",
+            "allowSyntheticDefaultImports": false,
+        }));
+        assert_eq!(config.import_interop, Some(ImportInterop::None));
+
+        // Node resolves the output, so ES modules importing it need the annotation
+        let config = convert(serde_json::json!({
+            "module": "commonjs",
+            "moduleResolution": "nodenext",
+        }));
+        assert_eq!(config.export_interop_annotation, Some(true));
+    }
+
+    #[test]
+    fn collect_warnings_reports_untranslated_options() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{
+                    "compilerOptions": {
+                        "strict": true,
+                        "noEmitHelpers": true,
+                        "jsx": "preserve"
+                    },
+                    "watchOptions": {},
+                    "files": ["src/index.ts"],
+                    "bndl": {
+                        "overrides": [
+                            { "files": ["src/**"], "compilerOptions": { "importHelpers": true } }
+                        ]
+                    }
+                }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+        let warnings: Vec<(String, ConfigWarningKind)> = converter
+            .collect_warnings()
+            .into_iter()
+            .map(|warning| (warning.option, warning.kind))
+            .collect();
+
+        // Options that only affect type checking, like `strict`, aren't worth a warning
+        assert_eq!(
+            warnings,
+            vec![
+                (String::from("files"), ConfigWarningKind::Unsupported),
+                (
+                    String::from("compilerOptions.noEmitHelpers"),
+                    ConfigWarningKind::Unsupported
+                ),
+                (
+                    String::from("compilerOptions.jsx"),
+                    ConfigWarningKind::Lossy
+                ),
+                (
+                    String::from("bndl.overrides[0].compilerOptions.importHelpers"),
+                    ConfigWarningKind::Lossy
+                ),
+            ]
+        );
+        assert!(converter
+            .check_strict_config()
+            .is_err_and(|err| err.starts_with("Found 4 unsupported tsconfig option(s)")));
+
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "strict": true, "target": "es2022" } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        assert!(create_converter(project.path())
+            .unwrap()
+            .check_strict_config()
+            .is_ok());
+    }
+
+    #[test]
+    fn explain_matches_the_emitted_config() {
+        let project = TestProject::new(&[
+            (
+                "base.json",
+                r#"{ "compilerOptions": { "experimentalDecorators": true } }"#,
+            ),
+            (
+                "tsconfig.json",
+                r#"{ "extends": "./base.json", "compilerOptions": { "module": "commonjs" } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let explanations = create_converter(project.path()).unwrap().explain().unwrap();
+
+        let legacy_decorator = explanations
+            .iter()
+            .find(|explanation| explanation.setting == "jsc.transform.legacyDecorator")
+            .unwrap();
+        assert_eq!(legacy_decorator.value, serde_json::json!(true));
+        assert_eq!(
+            legacy_decorator.sources[0].option,
+            "compilerOptions.experimentalDecorators"
+        );
+        assert!(legacy_decorator.sources[0]
+            .config
+            .as_ref()
+            .is_some_and(|config| config.ends_with("base.json")));
+
+        // Nothing that is left out of the emitted `.swcrc` is explained
+        for setting in ["jsc.lints", "jsc.output", "module.strictMode"] {
+            assert!(
+                !explanations
+                    .iter()
+                    .any(|explanation| explanation.setting.starts_with(setting)),
+                "{setting}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_swcrc_reports_untranslated_settings() {
+        let project = TestProject::new(&[(
+            ".swcrc",
+            r#"{
+                "minify": false,
+                "module": { "type": "commonjs", "strictMode": true, "lazy": true },
+                "jsc": {
+                    "target": "es2020",
+                    "parser": { "syntax": "typescript", "tsx": true },
+                    "lints": { "no-new-object": [] },
+                    "transform": {
+                        "legacyDecorator": true,
+                        "react": { "runtime": "automatic", "refresh": true }
+                    }
+                }
+            }"#,
+        )]);
+        let conversion = ReverseConversion::from_swcrc(&project.path().join(".swcrc")).unwrap();
+
+        let compiler_options = conversion.compiler_options;
+        assert_eq!(compiler_options.target.as_deref(), Some("es2020"));
+        assert_eq!(compiler_options.module.as_deref(), Some("commonjs"));
+        assert_eq!(compiler_options.jsx.as_deref(), Some("react-jsx"));
+        assert_eq!(compiler_options.experimentalDecorators, Some(true));
+
+        // Settings that are equal to the SWC defaults aren't worth a warning
+        assert_eq!(
+            conversion.untranslated,
+            vec!["jsc.transform.react.refresh", "module.lazy"]
+        );
+    }
+
+    #[test]
+    fn convert_to_format_renders_esbuild_and_babel_configs() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{
+                    "compilerOptions": {
+                        "target": "es2022",
+                        "module": "esnext",
+                        "jsx": "react-jsx",
+                        "outDir": "dist",
+                        "experimentalDecorators": true,
+                        "emitDecoratorMetadata": true
+                    }
+                }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+
+        let esbuild = converter.convert_to_format(OutputFormat::Esbuild).unwrap();
+        assert_eq!(esbuild["format"], "esm");
+        assert_eq!(esbuild["target"], "es2022");
+        assert_eq!(esbuild["jsx"], "automatic");
+        assert_eq!(
+            esbuild.pointer("/tsconfigRaw/compilerOptions/experimentalDecorators"),
+            Some(&serde_json::json!(true))
+        );
+
+        let babel = converter.convert_to_format(OutputFormat::Babel).unwrap();
+        assert_eq!(babel.pointer("/presets/0/0").unwrap(), "@babel/preset-env");
+        assert_eq!(
+            babel.pointer("/presets/0/1/modules"),
+            Some(&serde_json::json!(false))
+        );
+        assert_eq!(
+            babel.pointer("/presets/2/1/runtime"),
+            Some(&serde_json::json!("automatic"))
+        );
+        // The metadata plugin has to run before the decorators are compiled away
+        assert_eq!(
+            babel["plugins"],
+            serde_json::json!([
+                "babel-plugin-transform-typescript-metadata",
+                ["@babel/plugin-proposal-decorators", { "legacy": true }]
+            ])
+        );
+    }
+
+    #[test]
+    fn convert_to_esbuild_rejects_unsupported_modules() {
+        let project = TestProject::new(&[
+            (
+                "tsconfig.json",
+                r#"{ "compilerOptions": { "module": "amd" } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let converter = create_converter(project.path()).unwrap();
+
+        assert!(converter.convert_to_format(OutputFormat::Esbuild).is_err());
+        assert_eq!(
+            converter
+                .convert_to_format(OutputFormat::Babel)
+                .unwrap()
+                .pointer("/presets/0/1/modules"),
+            Some(&serde_json::json!("amd"))
+        );
+    }
+
+    #[test]
+    fn show_config_resolves_the_extends_chain_and_paths() {
+        let project = TestProject::new(&[
+            (
+                "base.json",
+                r#"{ "compilerOptions": { "strict": true, "baseUrl": "src", "paths": { "@/*": ["./*"] } } }"#,
+            ),
+            (
+                "tsconfig.json",
+                r#"{ "extends": "./base.json", "compilerOptions": { "outDir": "dist" } }"#,
+            ),
+            ("package.json", "{}"),
+        ]);
+        let config = create_converter(project.path())
+            .unwrap()
+            .show_config()
+            .unwrap();
+
+        assert!(config.get("extends").is_none());
+        assert_eq!(config["compilerOptions"]["strict"], true);
+        assert_eq!(config["compilerOptions"]["outDir"], "dist");
+
+        let target = config.pointer("/compilerOptions/paths/@~1*/0").unwrap();
+        assert_eq!(
+            PathBuf::from(target.as_str().unwrap()),
+            project.path().join("src").join("*")
+        );
+
+        let config_files: Vec<PathBuf> =
+            serde_json::from_value(config["configFiles"].clone()).unwrap();
+        assert_eq!(config_files.len(), 2);
+        assert!(config_files[0].ends_with("tsconfig.json"));
+        assert!(config_files[1].ends_with("base.json"));
     }
 }
@@ -1,8 +1,14 @@
-use bndl_convert::{Converter, CreateConverterOptions, SerializableOptions};
-use clap::{ArgAction, Command};
+use bndl_convert::{
+    remove_unwanted_values, Converter, CreateConverterOptions, OutputFormat, ReverseConversion,
+};
+use bndl_deps::Manager;
+use clap::{ArgAction, ArgMatches, Command};
+use log::debug;
 use serde_json::Value;
-use std::{env, path::PathBuf};
-use swc::config::Options;
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
 
 fn cli() -> Command {
     Command::new(env!("CARGO_PKG_NAME"))
@@ -17,70 +23,244 @@ fn cli() -> Command {
                 .help("Minify the output bundle")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            clap::Arg::new("format")
+                .short('f')
+                .long("format")
+                .help("The tool to generate the config for")
+                .value_parser(["swc", "esbuild", "babel"])
+                .default_value("swc")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            clap::Arg::new("swcrc")
+                .long("swcrc")
+                .help("Merge a .swcrc over the options converted from the tsconfig.json instead of the bndl.swcrc next to it, which is merged automatically. Defaults to the .swcrc in the current directory")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value(".swcrc")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            clap::Arg::new("strict-config")
+                .long("strict-config")
+                .help("Fail when the tsconfig.json contains options that can't be translated (exactly)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("from-swcrc")
+                .long("from-swcrc")
+                .help("Convert an .swcrc back to tsconfig.json compilerOptions, defaults to the .swcrc in the current directory")
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value(".swcrc")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            clap::Arg::new("show-config")
+                .long("show-config")
+                .help("Print the tsconfig.json merged with its extends chain, like tsc --showConfig")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("explain")
+                .long("explain")
+                .help("Explain which tsconfig option produced each SWC setting")
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             clap::Arg::new("save")
                 .short('s')
                 .long("save")
-                .help("Save the generated .swcrc to the current directory")
+                .help("Save the generated config (.swcrc, esbuild.json or babel.config.json) to the current directory")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("output")
+                .short('o')
+                .long("output")
+                .help("The path to save (or check with --check) the generated config at")
+                .conflicts_with("workspace")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            clap::Arg::new("workspace")
+                .long("workspace")
+                .help("Convert the tsconfig.json of every package in the workspace, saving the config next to it with --save")
+                .conflicts_with_all(["from-swcrc", "show-config", "explain"])
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            clap::Arg::new("check")
+                .long("check")
+                .help("Verify the config in the current directory is in sync with the tsconfig.json without saving")
+                .conflicts_with("save")
                 .action(ArgAction::SetTrue),
         )
 }
 
-fn remove_unwanted_values(value: &mut Value) -> Value {
-    match value {
-        Value::Object(map) => {
-            let keys_to_remove: Vec<String> = map
-                .iter()
-                .filter(|(_, v)| v.is_null() || (v.is_string() && v.to_string().is_empty()))
-                .map(|(k, _)| k.clone())
-                .collect();
-
-            // Remove keys with `null` values
-            for key in keys_to_remove {
-                map.remove(&key);
-            }
+/// Remove `null` values and empty objects from the config before logging
+fn parse_options_before_logging(mut value: Value) -> Value {
+    remove_unwanted_values(&mut value);
+    value
+}
+
+/// Collects a line for every setting that differs between the existing and the generated config
+fn diff_options(
+    path: String,
+    existing: Option<&Value>,
+    generated: Option<&Value>,
+    diff: &mut Vec<String>,
+) {
+    match (existing, generated) {
+        (Some(Value::Object(existing)), Some(Value::Object(generated))) => {
+            let mut keys: Vec<&String> = existing.keys().chain(generated.keys()).collect();
+            keys.sort();
+            keys.dedup();
 
-            // Now, iterate over the map and recursively remove on each value
-            let empty_keys: Vec<String> = map
-                .iter_mut()
-                .filter_map(|(k, v)| {
-                    remove_unwanted_values(v); // Recursively clean each value
-                    if v.is_object() && v.as_object().map(|m| m.is_empty()).unwrap_or(false) {
-                        Some(k.clone()) // Collect keys of now empty objects for removal
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            // Remove keys that have become empty objects after the recursive cleaning
-            for key in empty_keys {
-                map.remove(&key);
+            for key in keys {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+
+                diff_options(path, existing.get(key), generated.get(key), diff);
             }
         }
-        Value::Array(vec) => {
-            // Clean each element in the array
-            for v in vec.iter_mut() {
-                remove_unwanted_values(v);
+        (existing, generated) if existing == generated => (),
+        (existing, generated) => {
+            if let Some(existing) = existing {
+                diff.push(format!("- {path}: {existing}"));
+            }
+
+            if let Some(generated) = generated {
+                diff.push(format!("+ {path}: {generated}"));
             }
-            // Remove `null` elements and empty objects from the array
-            vec.retain(|v| {
-                !(v.is_null()
-                    || v.is_object() && v.as_object().map(|m| m.is_empty()).unwrap_or(false))
-            });
         }
-        // For `null` or other types, do nothing
-        _ => (),
     }
+}
+
+fn save_config(options: &Value, output_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let config = serde_json::to_string_pretty(options).unwrap();
+    std::fs::write(output_path, config)
+        .map_err(|err| format!("Unable to write {}: {err}", output_path.display()))?;
+    println!("Saved config to {}", output_path.display());
 
-    value.to_owned()
+    Ok(())
 }
 
-/// Remove `null` values and empty objects from the config before logging
-fn parse_options_before_logging(options: Options) -> Value {
-    let mut value = serde_json::to_value(SerializableOptions::from(&options)).unwrap();
+/// Compares the existing config to the generated options and fails when they drifted apart
+fn check_config(options: &Value, config_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(config_path)
+        .map_err(|err| format!("Unable to read {}: {err}", config_path.display()))?;
+    let existing: Value = serde_json::from_str(&content)
+        .map_err(|err| format!("Unable to parse {}: {err}", config_path.display()))?;
+
+    let mut diff = vec![];
+    diff_options(
+        String::new(),
+        Some(&parse_options_before_logging(existing)),
+        Some(options),
+        &mut diff,
+    );
+
+    if !diff.is_empty() {
+        for line in &diff {
+            eprintln!("{line}");
+        }
+
+        return Err(format!(
+            "{} is out of sync with the tsconfig.json, run `bndl-convert --save` to update it",
+            config_path.display()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Converts the `tsconfig.json` of a single workspace package and saves or checks the config
+/// next to it, returning the generated options
+fn convert_package(
+    matches: &ArgMatches,
+    format: OutputFormat,
+    manager: &Manager,
+    config_path: &Path,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let converter = Converter::from_path(
+        config_path,
+        CreateConverterOptions {
+            minify_output: Some(matches.get_flag("minify")),
+            swcrc_path: matches.get_one::<String>("swcrc").map(PathBuf::from),
+            manager: Some(manager.clone()),
+            project_dir: config_path.parent().map(Path::to_path_buf),
+            ..Default::default()
+        },
+    )?;
 
-    remove_unwanted_values(&mut value)
+    if matches.get_flag("strict-config") {
+        converter.check_strict_config()?;
+    }
+
+    let options = parse_options_before_logging(converter.convert_to_format(format)?);
+    let output_path = config_path.with_file_name(format.file_name());
+
+    if matches.get_flag("check") {
+        check_config(&options, &output_path)?;
+        println!(
+            "{} is in sync with the tsconfig.json",
+            output_path.display()
+        );
+    } else if matches.get_flag("save") {
+        save_config(&options, &output_path)?;
+    }
+
+    Ok(options)
+}
+
+/// Converts every package in the workspace that has a `tsconfig.json`, resolving the paths
+/// in each config relative to its own package. Without `--save` or `--check` the results are
+/// reported as JSON, keyed by package name.
+fn convert_workspace(
+    matches: &ArgMatches,
+    format: OutputFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let manager = Manager::new()?;
+
+    let mut packages: Vec<(&String, &PathBuf)> = manager.packages.iter().collect();
+    packages.sort();
+
+    let mut results = serde_json::Map::new();
+    let mut failure_count = 0;
+
+    for (name, package_dir) in packages {
+        let config_path = package_dir.join("tsconfig.json");
+        if !config_path.exists() {
+            debug!("Skipping {} since it has no tsconfig.json", name);
+            continue;
+        }
+
+        let result = match convert_package(matches, format, &manager, &config_path) {
+            Ok(options) => options,
+            Err(err) => {
+                failure_count += 1;
+                eprintln!("{name}: {err}");
+                serde_json::json!({ "error": err.to_string() })
+            }
+        };
+        results.insert(name.clone(), result);
+    }
+
+    if !matches.get_flag("check") && !matches.get_flag("save") {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+
+    if failure_count > 0 {
+        return Err(format!("Failed to convert {failure_count} package(s)").into());
+    }
+
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -89,6 +269,31 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let matches = cli().get_matches();
     let minify_output = matches.get_flag("minify");
     let should_save = matches.get_flag("save");
+    let format = match matches.get_one::<String>("format").map(String::as_str) {
+        Some("esbuild") => OutputFormat::Esbuild,
+        Some("babel") => OutputFormat::Babel,
+        _ => OutputFormat::Swc,
+    };
+
+    if let Some(swcrc_path) = matches.get_one::<String>("from-swcrc") {
+        let conversion = ReverseConversion::from_swcrc(&PathBuf::from(swcrc_path))?;
+        for setting in &conversion.untranslated {
+            eprintln!("Warning: {setting} has no tsconfig.json equivalent");
+        }
+
+        let tsconfig = serde_json::json!({ "compilerOptions": conversion.compiler_options });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&parse_options_before_logging(tsconfig)).unwrap()
+        );
+
+        return Ok(());
+    }
+
+    if matches.get_flag("workspace") {
+        return convert_workspace(&matches, format);
+    }
+
     let filename = match matches.subcommand() {
         Some((query, _)) => query,
         _ => "tsconfig.json",
@@ -97,19 +302,168 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &PathBuf::from(filename),
         CreateConverterOptions {
             minify_output: Some(minify_output),
+            swcrc_path: matches.get_one::<String>("swcrc").map(PathBuf::from),
             ..Default::default()
         },
     )?;
-    let options = parse_options_before_logging(converter.convert());
-    let config = serde_json::to_string_pretty(&options).unwrap();
 
-    if should_save {
-        let output_path = PathBuf::from(".swcrc");
-        std::fs::write(&output_path, config).unwrap();
-        println!("Saved config to {}", output_path.display());
+    if matches.get_flag("strict-config") {
+        converter.check_strict_config()?;
+    }
+
+    if matches.get_flag("show-config") {
+        let config = parse_options_before_logging(converter.show_config()?);
+        println!("{}", serde_json::to_string_pretty(&config).unwrap());
+
+        return Ok(());
+    }
+
+    if matches.get_flag("explain") {
+        for explanation in converter.explain()? {
+            println!("{} = {}", explanation.setting, explanation.value);
+
+            if explanation.sources.is_empty() {
+                println!("    bndl default");
+            }
+
+            for source in explanation.sources {
+                match source.config {
+                    Some(config) => println!("    {} in {}", source.option, config.display()),
+                    None => println!("    {} flag", source.option),
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    let options = parse_options_before_logging(converter.convert_to_format(format)?);
+    let output_path = match matches.get_one::<String>("output") {
+        Some(output_path) => PathBuf::from(output_path),
+        None => PathBuf::from(format.file_name()),
+    };
+
+    if matches.get_flag("check") {
+        check_config(&options, &output_path)?;
+        println!(
+            "{} is in sync with the tsconfig.json",
+            output_path.display()
+        );
+    } else if should_save || matches.contains_id("output") {
+        save_config(&options, &output_path)?;
     } else {
         println!("{}", serde_json::to_string_pretty(&options).unwrap());
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A project in a unique temporary directory that's removed again when dropped
+    struct TestProject(PathBuf);
+
+    impl TestProject {
+        fn new(files: &[(&str, &str)]) -> Self {
+            static COUNT: AtomicUsize = AtomicUsize::new(0);
+            let project_dir = env::temp_dir().join(format!(
+                "bndl-convert-main-test-{}-{}",
+                std::process::id(),
+                COUNT.fetch_add(1, Ordering::Relaxed)
+            ));
+
+            for (path, content) in files {
+                let path = project_dir.join(path);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(path, content).unwrap();
+            }
+
+            Self(project_dir)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TestProject {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn diff_options_reports_changed_added_and_removed_settings() {
+        let existing = json!({
+            "minify": false,
+            "jsc": { "target": "es2020", "keepClassNames": true },
+        });
+        let generated = json!({
+            "minify": false,
+            "jsc": { "target": "es2022", "externalHelpers": true },
+        });
+
+        let mut diff = vec![];
+        diff_options(String::new(), Some(&existing), Some(&generated), &mut diff);
+
+        assert_eq!(
+            diff,
+            vec![
+                "+ jsc.externalHelpers: true",
+                "- jsc.keepClassNames: true",
+                "- jsc.target: \"es2020\"",
+                "+ jsc.target: \"es2022\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_config_ignores_null_values_in_the_existing_config() {
+        let project = TestProject::new(&[(
+            ".swcrc",
+            r#"{ "minify": false, "jsc": { "target": "es2022", "baseUrl": null } }"#,
+        )]);
+        let config_path = project.path().join(".swcrc");
+        let options = json!({ "minify": false, "jsc": { "target": "es2022" } });
+
+        assert!(check_config(&options, &config_path).is_ok());
+        assert!(check_config(&json!({ "minify": true }), &config_path).is_err());
+    }
+
+    #[test]
+    fn convert_package_saves_the_config_next_to_the_tsconfig() {
+        let project = TestProject::new(&[
+            (
+                "packages/api/tsconfig.json",
+                r#"{ "compilerOptions": { "target": "es2022", "outDir": "dist" } }"#,
+            ),
+            ("packages/api/package.json", r#"{ "name": "api" }"#),
+        ]);
+        let manager = Manager {
+            root: project.path().to_path_buf(),
+            packages: [(
+                String::from("api"),
+                project.path().join("packages").join("api"),
+            )]
+            .into(),
+        };
+        let matches = cli().get_matches_from(["bndl-convert", "--workspace", "--save"]);
+        let config_path = project.path().join("packages/api/tsconfig.json");
+
+        let options = convert_package(&matches, OutputFormat::Swc, &manager, &config_path).unwrap();
+        let saved_path = project.path().join("packages/api/.swcrc");
+        let saved: Value =
+            serde_json::from_str(&std::fs::read_to_string(&saved_path).unwrap()).unwrap();
+
+        assert_eq!(saved, options);
+        assert_eq!(options.pointer("/jsc/target"), Some(&json!("es2022")));
+
+        // The saved config is in sync, so checking it passes
+        let matches = cli().get_matches_from(["bndl-convert", "--workspace", "--check"]);
+        assert!(convert_package(&matches, OutputFormat::Swc, &manager, &config_path).is_ok());
+    }
+}
@@ -39,10 +39,15 @@ impl Default for WorkspacesConfig {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
-struct PackageJson {
+pub struct PackageJson {
     pub name: String,
+    #[serde(rename = "type")]
+    pub module_type: Option<String>,
     pub workspaces: Option<WorkspacesConfig>,
     pub dependencies: Option<HashMap<String, String>>,
+    pub engines: Option<HashMap<String, String>>,
+    /// Configuration for `bndl` itself, interpreted by its consumers
+    pub bndl: Option<serde_json::Value>,
 }
 
 impl PackageJson {